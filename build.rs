@@ -0,0 +1,16 @@
+//! Build the original C solutions for day 9 into the crate, so that they can
+//! be checked against the Rust version. See `advent::days::day9::c`.
+
+fn main() {
+    let sources = ["src/day9part1.c", "src/day9part2.c"];
+
+    for source in &sources {
+        println!("cargo:rerun-if-changed={}", source);
+    }
+
+    cc::Build::new()
+        .files(&sources)
+        .define("ADVENT_NO_MAIN", None)
+        .flag_if_supported("-std=c99")
+        .compile("day9c");
+}
@@ -0,0 +1,212 @@
+//! A global allocator wrapper that keeps running totals of how much memory is
+//! in use, so that the harness can report the peak allocation of a solution.
+//! Counting is only switched on inside `measure`; the rest of the time, such
+//! as during `--bench` runs, each allocation only pays for checking a flag.
+
+use std::alloc::{GlobalAlloc, Layout};
+use std::fmt::{self, Display, Formatter};
+use std::mem;
+use std::ptr;
+use std::sync::atomic::{AtomicBool, AtomicIsize, AtomicUsize, Ordering};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+// Memory in use relative to the start of the current `measure` call. This
+// goes negative when memory from before the call is freed.
+static CURRENT: AtomicIsize = AtomicIsize::new(0);
+static PEAK: AtomicIsize = AtomicIsize::new(0);
+static COUNT: AtomicUsize = AtomicUsize::new(0);
+static TOTAL: AtomicUsize = AtomicUsize::new(0);
+
+/// Wraps another allocator, counting allocations made through it while a
+/// `measure` call is running. There should only be one of these, as the
+/// `#[global_allocator]`.
+pub struct Tracking<A>(pub A);
+
+impl<A> Tracking<A> {
+    #[inline]
+    fn record_alloc(size: usize) {
+        if ENABLED.load(Ordering::Relaxed) {
+            let current = CURRENT.fetch_add(size as isize, Ordering::Relaxed) + size as isize;
+            PEAK.fetch_max(current, Ordering::Relaxed);
+            COUNT.fetch_add(1, Ordering::Relaxed);
+            TOTAL.fetch_add(size, Ordering::Relaxed);
+        }
+    }
+
+    #[inline]
+    fn record_dealloc(size: usize) {
+        if ENABLED.load(Ordering::Relaxed) {
+            CURRENT.fetch_sub(size as isize, Ordering::Relaxed);
+        }
+    }
+}
+
+unsafe impl<A: GlobalAlloc> GlobalAlloc for Tracking<A> {
+    #[inline]
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = self.0.alloc(layout);
+        if !ptr.is_null() {
+            Self::record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    #[inline]
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = self.0.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            Self::record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    #[inline]
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        self.0.dealloc(ptr, layout);
+        Self::record_dealloc(layout.size());
+    }
+
+    #[inline]
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = self.0.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            Self::record_dealloc(layout.size());
+            Self::record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Allocation activity over the course of a `measure` call
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Usage {
+    /// The most memory in use at once, above what was in use at the start
+    pub peak_bytes: usize,
+
+    /// The total number of bytes allocated, including memory that was freed
+    pub total_bytes: usize,
+
+    /// The number of allocations, including reallocations
+    pub count: usize,
+}
+
+/// Run a function and measure its allocations. This counts allocations from
+/// every thread, so it's only meaningful when nothing else is running, and
+/// calls can't be nested.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Usage) {
+    CURRENT.store(0, Ordering::Relaxed);
+    PEAK.store(0, Ordering::Relaxed);
+    COUNT.store(0, Ordering::Relaxed);
+    TOTAL.store(0, Ordering::Relaxed);
+
+    ENABLED.store(true, Ordering::SeqCst);
+    let result = f();
+    ENABLED.store(false, Ordering::SeqCst);
+
+    let usage = Usage {
+        peak_bytes: PEAK.load(Ordering::Relaxed).max(0) as usize,
+        total_bytes: TOTAL.load(Ordering::Relaxed),
+        count: COUNT.load(Ordering::Relaxed),
+    };
+
+    (result, usage)
+}
+
+impl Display for Usage {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} bytes allocated in {} allocations, peak {} bytes in use",
+            self.total_bytes, self.count, self.peak_bytes
+        )
+    }
+}
+
+/// Process-wide memory statistics, from the OS and from jemalloc itself.
+/// Unlike `Usage`, these aren't scoped to a single `measure` call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MemoryStats {
+    /// The high-water mark of the process's resident set size, over the
+    /// whole run so far: this includes reading the input, and anything else
+    /// the process did before the solve
+    pub process_peak_resident_bytes: Option<usize>,
+
+    /// Bytes currently allocated by the application, according to jemalloc
+    pub jemalloc_allocated: Option<usize>,
+
+    /// Bytes in physically resident pages mapped by jemalloc
+    pub jemalloc_resident: Option<usize>,
+}
+
+impl MemoryStats {
+    pub fn read() -> Self {
+        // jemalloc caches its statistics; advancing the epoch refreshes them
+        let epoch_refreshed = mallctl_write(b"epoch\0", 1u64).is_some();
+
+        MemoryStats {
+            process_peak_resident_bytes: process_peak_resident_bytes(),
+            jemalloc_allocated: mallctl_read(b"stats.allocated\0").filter(|_| epoch_refreshed),
+            jemalloc_resident: mallctl_read(b"stats.resident\0").filter(|_| epoch_refreshed),
+        }
+    }
+}
+
+impl Display for MemoryStats {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        fn field(value: Option<usize>) -> String {
+            value.map_or_else(|| "unknown".to_string(), |bytes| format!("{} bytes", bytes))
+        }
+
+        write!(
+            f,
+            "process peak resident {}; jemalloc {} allocated, {} resident",
+            field(self.process_peak_resident_bytes),
+            field(self.jemalloc_allocated),
+            field(self.jemalloc_resident),
+        )
+    }
+}
+
+fn mallctl_read(name: &[u8]) -> Option<usize> {
+    let mut value: usize = 0;
+    let mut len = mem::size_of::<usize>();
+
+    let result = unsafe {
+        jemalloc_sys::mallctl(
+            name.as_ptr() as *const _,
+            &mut value as *mut usize as *mut _,
+            &mut len,
+            ptr::null_mut(),
+            0,
+        )
+    };
+
+    if result == 0 { Some(value) } else { None }
+}
+
+fn mallctl_write(name: &[u8], mut value: u64) -> Option<()> {
+    let result = unsafe {
+        jemalloc_sys::mallctl(
+            name.as_ptr() as *const _,
+            ptr::null_mut(),
+            ptr::null_mut(),
+            &mut value as *mut u64 as *mut _,
+            mem::size_of::<u64>(),
+        )
+    };
+
+    if result == 0 { Some(()) } else { None }
+}
+
+fn process_peak_resident_bytes() -> Option<usize> {
+    let mut usage: libc::rusage = unsafe { mem::zeroed() };
+
+    if unsafe { libc::getrusage(libc::RUSAGE_SELF, &mut usage) } != 0 {
+        return None;
+    }
+
+    // ru_maxrss is in kilobytes on Linux, but bytes on macOS
+    let scale = if cfg!(target_os = "macos") { 1 } else { 1024 };
+    Some(usage.ru_maxrss as usize * scale)
+}
@@ -0,0 +1,117 @@
+//! Statistical benchmarking for the harness's `--bench` mode. The solver is
+//! run repeatedly on the same input after a few warmup runs, and the timings
+//! are summarized and appended to a CSV file so that they can be compared
+//! across days and across changes.
+
+use std::fmt::{self, Display, Formatter};
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::error::ParseError;
+use crate::harness::{solve, timed};
+use crate::solution::{Part, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub std_dev: Duration,
+}
+
+impl Stats {
+    /// Summarize a set of timings. Returns `None` if there are no timings.
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let runs = sorted.len();
+        let min = *sorted.first()?;
+
+        // With an odd number of runs, these are both the middle element
+        let median = (sorted[(runs - 1) / 2] + sorted[runs / 2]) / 2;
+
+        let nanos: Vec<f64> = sorted.iter().map(|d| d.as_nanos() as f64).collect();
+        let mean = nanos.iter().sum::<f64>() / runs as f64;
+
+        // Sample standard deviation; a single run has no spread
+        let variance = if runs > 1 {
+            nanos.iter().map(|n| (n - mean).powi(2)).sum::<f64>() / (runs - 1) as f64
+        } else {
+            0.0
+        };
+
+        Some(Stats {
+            runs,
+            min,
+            median,
+            mean: Duration::from_nanos(mean as u64),
+            std_dev: Duration::from_nanos(variance.sqrt() as u64),
+        })
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} runs: min {:?}, median {:?}, mean {:?}, std dev {:?}",
+            self.runs, self.min, self.median, self.mean, self.std_dev
+        )
+    }
+}
+
+/// Solve the given part `runs` times, after `runs / 10` (at least one) warmup
+/// runs, and return the answer along with the timing statistics.
+pub fn bench<S: Solution>(part: Part, input: &str, runs: usize) -> Result<(String, Stats), ParseError> {
+    let warmup = runs.div_ceil(10).max(1);
+    let mut answer = String::new();
+
+    for _ in 0..warmup {
+        answer = solve::<S>(part, input)?;
+    }
+
+    let mut samples = Vec::with_capacity(runs);
+
+    for _ in 0..runs {
+        let (result, duration) = timed(|| solve::<S>(part, input));
+        answer = result?;
+        samples.push(duration);
+    }
+
+    let stats = Stats::from_samples(&samples).expect("Benchmarks need at least one run");
+    Ok((answer, stats))
+}
+
+pub const CSV_HEADER: &str = "timestamp,day,part,runs,min_ns,median_ns,mean_ns,std_dev_ns";
+
+/// Append a benchmark result to a CSV file, writing the header first if the
+/// file is new. Times are in nanoseconds; the timestamp is in Unix seconds.
+pub fn append_csv(path: &Path, day: u32, part: Part, stats: &Stats) -> io::Result<()> {
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+
+    if file.metadata()?.len() == 0 {
+        writeln!(file, "{}", CSV_HEADER)?;
+    }
+
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    writeln!(
+        file,
+        "{},{},{},{},{},{},{},{}",
+        timestamp,
+        day,
+        part,
+        stats.runs,
+        stats.min.as_nanos(),
+        stats.median.as_nanos(),
+        stats.mean.as_nanos(),
+        stats.std_dev.as_nanos(),
+    )
+}
@@ -0,0 +1,7 @@
+use advent::days::day10::Day10;
+use advent::harness;
+use advent::solution::Part;
+
+fn main() {
+    harness::main::<Day10>(Part::One)
+}
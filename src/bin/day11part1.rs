@@ -0,0 +1,7 @@
+use advent::days::day11::Day11;
+use advent::harness;
+use advent::solution::Part;
+
+fn main() {
+    harness::main::<Day11>(Part::One)
+}
@@ -0,0 +1,7 @@
+use advent::days::day13::Day13;
+use advent::harness;
+use advent::solution::Part;
+
+fn main() {
+    harness::main::<Day13>(Part::Two)
+}
@@ -0,0 +1,7 @@
+use advent::days::day15::Day15;
+use advent::harness;
+use advent::solution::Part;
+
+fn main() {
+    harness::main::<Day15>(Part::One)
+}
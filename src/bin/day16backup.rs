@@ -0,0 +1,7 @@
+use advent::days::day16::Day16Backup;
+use advent::harness;
+use advent::solution::Part;
+
+fn main() {
+    harness::main::<Day16Backup>(Part::One)
+}
@@ -0,0 +1,7 @@
+use advent::days::day16::Day16;
+use advent::harness;
+use advent::solution::Part;
+
+fn main() {
+    harness::main::<Day16>(Part::Two)
+}
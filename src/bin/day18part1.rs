@@ -0,0 +1,7 @@
+use advent::days::day18::Day18;
+use advent::harness;
+use advent::solution::Part;
+
+fn main() {
+    harness::main::<Day18>(Part::One)
+}
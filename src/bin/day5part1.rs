@@ -0,0 +1,7 @@
+use advent::days::day5::Day5;
+use advent::harness;
+use advent::solution::Part;
+
+fn main() {
+    harness::main::<Day5>(Part::One)
+}
@@ -0,0 +1,26 @@
+//! Draw the day 5 Voronoi diagram, for debugging. Prints it as ASCII, or
+//! writes it as a PPM image with `--ppm FILE`.
+
+use std::path::Path;
+use std::process::exit;
+
+use advent::days::day5::{Day5, Voronoi};
+use advent::harness;
+use advent::render;
+
+fn main() {
+    let (seeds, args) = harness::load::<Day5>(&[("--ppm", "FILE")]);
+    let voronoi = Voronoi::new(&seeds).expect("parse rejects empty input");
+
+    match args.value("--ppm") {
+        None => println!("{}", voronoi.render_ascii()),
+        Some(path) => {
+            let result = render::save_ppm(Path::new(path), &voronoi.bounds(), |cell| voronoi.colour(cell));
+
+            if let Err(err) = result {
+                eprintln!("Error writing {}: {}", path, err);
+                exit(1);
+            }
+        }
+    }
+}
@@ -0,0 +1,15 @@
+//! Print the day 7 dependency graph in Graphviz DOT format, with the step
+//! order and the critical path of the 5-worker schedule highlighted. Render
+//! it with something like `day7dot | dot -Tsvg > day7.svg`.
+
+use advent::days::day7::{default_duration, Day7, Schedule, WORKERS};
+use advent::harness;
+
+fn main() {
+    let (steps, _) = harness::load::<Day7>(&[]);
+
+    let order = steps.lexicographic_order().unwrap();
+    let critical_path = Schedule::run(&steps, WORKERS, default_duration).critical_path(&steps);
+
+    println!("{}", steps.dot().with_order(&order).with_critical_path(&critical_path));
+}
@@ -0,0 +1,23 @@
+//! Print the 5-worker day 7 schedule as a Gantt chart, followed by every
+//! start and finish in time order.
+
+use advent::days::day7::{default_duration, Day7, EventKind, Schedule, WORKERS};
+use advent::harness;
+
+fn main() {
+    let (steps, _) = harness::load::<Day7>(&[]);
+    let schedule = Schedule::run(&steps, WORKERS, default_duration);
+
+    print!("{}", schedule.gantt());
+    println!();
+
+    for event in schedule.events() {
+        let action = match event.kind {
+            EventKind::Start => "starts",
+            EventKind::Finish => "finishes",
+        };
+        println!("{:>5}: worker {} {} {}", event.time, event.worker + 1, action, event.step);
+    }
+
+    println!("Total time: {}", schedule.total_time);
+}
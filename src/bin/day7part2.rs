@@ -0,0 +1,7 @@
+use advent::days::day7::Day7;
+use advent::harness;
+use advent::solution::Part;
+
+fn main() {
+    harness::main::<Day7>(Part::Two)
+}
@@ -0,0 +1,7 @@
+use advent::days::day8::Day8Arena;
+use advent::harness;
+use advent::solution::Part;
+
+fn main() {
+    harness::main::<Day8Arena>(Part::Two)
+}
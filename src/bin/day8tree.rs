@@ -0,0 +1,11 @@
+//! Print the day 8 license tree with one node per line, indented by depth,
+//! along with each node's metadata and value.
+
+use advent::days::day8::Day8;
+use advent::harness;
+
+fn main() {
+    let (root, _) = harness::load::<Day8>(&[]);
+
+    print!("{}", root.pretty());
+}
@@ -0,0 +1,7 @@
+use advent::days::day9::Day9;
+use advent::harness;
+use advent::solution::Part;
+
+fn main() {
+    harness::main::<Day9>(Part::Two)
+}
@@ -1,98 +0,0 @@
-#![allow(unused_imports)]
-
-use std::collections::{HashMap, HashSet};
-use std::error::Error;
-use std::fmt::{self, Display, Formatter};
-use std::hash::Hash;
-use std::io::{self, Read};
-use std::iter::{self, FromIterator, FusedIterator, Peekable};
-use std::mem::{replace, swap};
-use std::ops::Add;
-use std::process::exit;
-use std::rc::{Rc, Weak};
-use std::str::FromStr;
-use std::thread::sleep;
-use std::time::{Duration, Instant};
-
-use joinery::prelude::*;
-use lazy_static::lazy_static;
-use rayon::prelude::*;
-use regex::{self, Regex};
-use gridly::prelude::*;
-use gridly_grids::*;
-use generations::*;
-
-// DON'T TOUCH THIS
-#[inline(always)]
-fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
-    let start = Instant::now();
-    let result = f();
-    let end = Instant::now();
-    (result, end - start)
-}
-
-trait ReadString: Read {
-    fn read_string(&mut self) -> io::Result<String> {
-        let mut data = String::new();
-        self.read_to_string(&mut data).map(|_| data)
-    }
-}
-
-impl<T: Read> ReadString for T {}
-
-fn main() {
-    let ((), total_duration) = timed(move || {
-        let input = io::stdin().read_string().unwrap_or_else(|err| {
-            eprintln!("Error reading input: {}", err);
-            exit(1);
-        });
-
-        let (solution, duration) = timed(move || solve(&input));
-        println!("{}", solution);
-
-        eprintln!("Algorithm duration: {:?}", duration);
-    });
-    eprintln!("Total duration: {:?}", total_duration);
-}
-
-trait RegexExtractor<'t> {
-    fn field<T>(&self, index: usize) -> T
-    where
-        &'t str: Into<T>;
-
-    fn parse<T: FromStr>(&self, index: usize) -> T
-    where
-        T::Err: Display;
-}
-
-impl<'t> RegexExtractor<'t> for regex::Captures<'t> {
-    #[inline]
-    fn field<T>(&self, index: usize) -> T
-    where
-        &'t str: Into<T>,
-    {
-        self.get(index)
-            .unwrap_or_else(move || panic!("Group {} didn't match anything", index))
-            .as_str()
-            .into()
-    }
-
-    #[inline]
-    fn parse<T: FromStr>(&self, index: usize) -> T
-    where
-        T::Err: Display,
-    {
-        let field: &str = self.field(index);
-
-        field.parse().unwrap_or_else(move |err| {
-            panic!("Failed to parse group {} \"{}\": {}", index, field, err)
-        })
-    }
-}
-// CODE GOES HERE
-
-
-#[inline(always)]
-fn solve(input: &str) -> impl Display {
-
-}
@@ -9,7 +9,7 @@ typedef struct node_t {
 	struct node_t* right;
 } Node;
 
-Node* move_left(Node* node, size_t count) {
+static Node* move_left(Node* node, size_t count) {
 	while(count--) {
 		node = node->left;
 	}
@@ -17,7 +17,7 @@ Node* move_left(Node* node, size_t count) {
 	return node;
 }
 
-Node* move_right(Node* node, size_t count) {
+static Node* move_right(Node* node, size_t count) {
 	while(count--) {
 		node = node->right;
 	}
@@ -25,7 +25,7 @@ Node* move_right(Node* node, size_t count) {
 	return node;
 }
 
-void insert_left(Node* root, Node* new_node) {
+static void insert_left(Node* root, Node* new_node) {
 	Node* old_left = root->left;
 
 	new_node->left = old_left;
@@ -35,11 +35,7 @@ void insert_left(Node* root, Node* new_node) {
 	old_left->right = new_node;
 }
 
-void insert_right(Node* root, Node* new_node) {
-	insert_left(root->right, new_node);
-}
-
-void remove_node(Node* target) {
+static void remove_node(Node* target) {
 	target->left->right = target->right;
 	target->right->left = target->left;
 
@@ -48,18 +44,16 @@ void remove_node(Node* target) {
 }
 
 /**
- * Main function. Reads all of stdin into a buffer, calls solve()
+ * Play the marble game with num_players players, up to and including marble
+ * num_marbles, and return the winning score. num_players must be at least 1.
  */
-int main() {
+unsigned long day9part2_high_score(unsigned long num_players, unsigned long num_marbles) {
 	Node root_node;
 	root_node.value = 0;
 	root_node.left = &root_node;
 	root_node.right = &root_node;
 
-	const static unsigned long num_players = 459;
-	const static unsigned long num_marbles = 7210300;
-
-	unsigned long scores[num_players] = {0};
+	unsigned long* scores = calloc(num_players, sizeof(unsigned long));
 	size_t current_player = 0;
 
 	Node* new_node = 0;
@@ -105,5 +99,32 @@ int main() {
 		best_score = scores[player] > best_score ? scores[player] : best_score;
 	}
 
-	printf("%lu\n", best_score);
+	free(scores);
+
+	// Free the circle. The root node is on the stack, so skip it wherever it
+	// ended up.
+	Node* node = current_node;
+	do {
+		Node* next = node->right;
+		if(node != &root_node) {
+			free(node);
+		}
+		node = next;
+	} while(node != current_node);
+
+	if(new_node != &root_node) {
+		free(new_node);
+	}
+
+	return best_score;
+}
+
+/**
+ * Main function. Plays the game from the puzzle input and prints the winning
+ * score. Left out when this file is built into the Rust crate.
+ */
+#ifndef ADVENT_NO_MAIN
+int main() {
+	printf("%lu\n", day9part2_high_score(459, 7210300));
 }
+#endif
@@ -0,0 +1,159 @@
+#![allow(unused_imports, dead_code)]
+
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::io::{self, Read};
+use std::iter::{self, FromIterator, Peekable, FusedIterator};
+use std::mem::replace;
+use std::ops::Add;
+use std::process::exit;
+use std::rc::{Rc, Weak};
+use std::str::FromStr;
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+
+use joinery::prelude::*;
+use lazy_static::lazy_static;
+use rayon::prelude::*;
+use regex::{self, Regex};
+
+use crate::error::ParseError;
+use crate::geom::{Bounds, Point};
+use crate::harness::{self, FromCaptures, RegexExtractor};
+use crate::iter::BetterIterator;
+use crate::solution::{Solution, Unsolved};
+
+// CODE GOES HERE
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct Light {
+    position: Point,
+    velocity: Point,
+}
+
+impl Light {
+    fn advance(&mut self) {
+        self.position += self.velocity;
+    }
+}
+
+impl FromCaptures for Light {
+    fn from_captures(caps: &regex::Captures) -> Result<Self, ParseError> {
+        match (Point::from_named_captures(caps, "x", "y"), Point::from_named_captures(caps, "dx", "dy")) {
+            (Ok(position), Ok(velocity)) => Ok(Light { position, velocity }),
+            (position, velocity) => Err(ParseError::aggregate(position.err().into_iter().chain(velocity.err())).unwrap()),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Points(Vec<Light>);
+
+impl FromIterator<Light> for Points {
+    fn from_iter<I: IntoIterator<Item = Light>>(iter: I) -> Self {
+        Points(FromIterator::from_iter(iter))
+    }
+}
+
+impl Display for Points {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let min_y = self.0.first().unwrap().position.y;
+        let min_x = self.0.iter().map(|light| light.position.x).min().unwrap();
+
+        let mut y = min_y;
+        let mut x = min_x;
+
+        for Light { position: point, .. } in &self.0 {
+            // Advance row
+            if point.y > y {
+                x = min_x;
+
+                while point.y > y {
+                    y += 1;
+                    '\n'.fmt(f)?;
+                }
+            }
+
+            // Advance columns
+            while point.x > x {
+                x += 1;
+                ' '.fmt(f)?;
+            }
+
+            if point.x == x {
+                // Write point
+                'O'.fmt(f)?;
+                x += 1;
+            }
+        }
+
+        '\n'.fmt(f)?;
+
+        for _ in 0..=self.width() {
+            '-'.fmt(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Points {
+    fn advance(&mut self) {
+        self.0.iter_mut().for_each(|light| light.advance());
+        self.0.sort();
+    }
+
+    fn bounds(&self) -> Bounds {
+        Bounds::of(self.0.iter().map(|light| light.position)).unwrap()
+    }
+
+    fn height(&self) -> i64 {
+        self.bounds().height() - 1
+    }
+
+    fn width(&self) -> i64 {
+        self.bounds().width() - 1
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+
+    type Input = Points;
+    type Part1 = &'static str;
+    type Part2 = Unsolved;
+
+    fn parse(input: &str) -> Result<Points, ParseError> {
+        lazy_static! {
+            static ref PATTERN: Regex = Regex::new(
+                r"position=\s*<\s*(?P<x>-?\d+),\s*(?P<y>-?\d+)>\s*velocity=\s*<\s*(?P<dx>-?\d+),\s*(?P<dy>-?\d+)>"
+            ).unwrap();
+        }
+
+        harness::parse_lines(input, &PATTERN).map(Points)
+    }
+
+    fn part1(points: &Points) -> &'static str {
+        let mut points = points.clone();
+
+        eprintln!("{}", points.0.len());
+
+        let mut seconds = 0;
+        loop {
+            points.advance();
+            seconds += 1;
+
+            if points.height() < 20 {
+                eprintln!("time: {}\n{}", seconds, points);
+                sleep(Duration::from_secs(1));
+            }
+        }
+    }
+
+    fn part2(_points: &Points) -> Unsolved {
+        Unsolved
+    }
+}
@@ -0,0 +1,77 @@
+#![allow(unused_imports, dead_code)]
+
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::io::{self, Read};
+use std::iter::{self, FromIterator, Peekable, FusedIterator};
+use std::mem::replace;
+use std::ops::Add;
+use std::process::exit;
+use std::rc::{Rc, Weak};
+use std::str::FromStr;
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+
+use joinery::prelude::*;
+use lazy_static::lazy_static;
+use rayon::prelude::*;
+use regex::{self, Regex};
+
+use crate::error::ParseError;
+use crate::harness::RegexExtractor;
+use crate::iter::BetterIterator;
+use crate::solution::Solution;
+
+// CODE GOES HERE
+
+const SERIAL: i64 = 7347;
+
+const fn power_of(x: i64, y: i64) -> i64 {
+    ((((((x + 10) * y) + SERIAL) * (x + 10)) % 1000) / 100) - 5
+}
+
+fn power_3_of(x: i64, y: i64) -> i64 {
+    multi_power_of(x, y, 3)
+}
+
+fn multi_power_of(x: i64, y: i64, size: i64) -> i64 {
+    (0..size).cartesian(0..size)
+        .map(move |(dx, dy)| power_of(x + dx, y + dy))
+        .sum()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+
+    // The grid serial number is hardcoded in SERIAL
+    type Input = ();
+    type Part1 = String;
+    type Part2 = &'static str;
+
+    fn parse(_input: &str) -> Result<(), ParseError> {
+        Ok(())
+    }
+
+    fn part1(_input: &()) -> String {
+        let (x, y) = (1..=298).cartesian(1..=298)
+            .max_by_key(move |(x, y)| power_3_of(*x, *y))
+            .unwrap();
+
+        vec![x, y].join_with(",").to_string()
+    }
+
+    fn part2(_input: &()) -> &'static str {
+        (2..100).for_each(move |size| {
+            let (x, y, score) = (1 ..= 300 - size).cartesian(1 ..= 300 - size)
+                .map(move |(x, y)| (x, y, multi_power_of(x, y, size)))
+                .max_by_key(|(_x, _y, score)| *score).unwrap();
+
+            eprintln!("{},{},{}: {}", x, y, size, score)
+        });
+
+        "DONE"
+    }
+}
@@ -0,0 +1,226 @@
+#![allow(unused_imports, dead_code)]
+
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::hash::Hash;
+use std::io::{self, Read};
+use std::iter::{self, FromIterator, FusedIterator, Peekable};
+use std::mem::{replace, swap};
+use std::ops::Add;
+use std::process::exit;
+use std::rc::{Rc, Weak};
+use std::str::FromStr;
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+
+use joinery::prelude::*;
+use lazy_static::lazy_static;
+use rayon::prelude::*;
+use regex::{self, Regex};
+use lazy_format::lazy_format;
+
+use gridly::prelude::*;
+use gridly_grids::*;
+
+use crate::error::ParseError;
+use crate::harness::RegexExtractor;
+use crate::iter::BetterIterator;
+use crate::solution::{Solution, Unsolved};
+
+// CODE GOES HERE
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+enum Track {
+    #[default]
+    Empty,
+    Vertical,
+    Horizontal,
+    RightBend,
+    LeftBend,
+    Intersection,
+}
+
+impl Track {
+    fn as_char(self) -> char {
+        match self {
+            Track::Empty => ' ',
+            Track::Vertical => '|',
+            Track::Horizontal => '–',
+            Track::RightBend => '/',
+            Track::LeftBend => '\\',
+            Track::Intersection => '+',
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+enum Turn {
+    Left,
+    Straight,
+    Right,
+}
+
+impl Turn {
+    fn next(self) -> Self {
+        match self {
+            Turn::Left => Turn::Straight,
+            Turn::Straight => Turn::Right,
+            Turn::Right => Turn::Left,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+struct Cart {
+    direction: Direction,
+    next_turn: Turn,
+}
+
+impl Cart {
+    fn new(direction: Direction) -> Self {
+        Self {
+            direction,
+            next_turn: Turn::Left,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Tracks {
+    grid: VecGrid<Track>,
+    carts: HashMap<Location, Cart>,
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u32 = 13;
+
+    type Input = Tracks;
+    type Part1 = Unsolved;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Tracks, ParseError> {
+        let lines: Vec<&str> = input.lines().map(|line| line.trim_end()).collect();
+        let num_rows = Rows(lines.len() as isize);
+        let num_columns = Columns(lines.iter().map(|line| line.len()).max().unwrap_or(0) as isize);
+
+        let mut grid: VecGrid<Track> = VecGrid::new((num_rows, num_columns)).unwrap();
+        let mut carts: HashMap<Location, Cart> = HashMap::new();
+
+        for (line, row) in lines.iter().zip(RowRange::span(0.into(), num_rows)) {
+            for (c, column) in line.chars().zip(ColumnRange::span(0.into(), num_columns)) {
+                let location = row + column;
+
+                let track = match c {
+                    ' ' => Track::Empty,
+                    '+' => Track::Intersection,
+                    '|' => Track::Vertical,
+                    '-' => Track::Horizontal,
+                    '/' => Track::RightBend,
+                    '\\' => Track::LeftBend,
+
+                    '^' => {
+                        carts.insert(location, Cart::new(Up));
+                        Track::Vertical
+                    }
+                    'v' | 'V' => {
+                        carts.insert(location, Cart::new(Down));
+                        Track::Vertical
+                    }
+                    '>' => {
+                        carts.insert(location, Cart::new(Right));
+                        Track::Horizontal
+                    }
+                    '<' => {
+                        carts.insert(location, Cart::new(Left));
+                        Track::Horizontal
+                    }
+                    c => return Err(ParseError::at(
+                        row.0 as usize + 1,
+                        column.0 as usize + 1,
+                        format!("Unexpected character: {:?}", c),
+                    )),
+                };
+
+                grid.set(location, track).unwrap()
+            }
+        }
+
+        // Collisions remove carts in pairs, so part 2 only ends with one cart
+        // left if there was an odd number to begin with
+        if carts.len().is_multiple_of(2) {
+            return Err(ParseError::new(format!("Expected an odd number of carts, found {}", carts.len())));
+        }
+
+        Ok(Tracks { grid, carts })
+    }
+
+    fn part1(_tracks: &Tracks) -> Unsolved {
+        Unsolved
+    }
+
+    fn part2(tracks: &Tracks) -> String {
+        let grid = &tracks.grid;
+        let mut carts = tracks.carts.clone();
+
+        let mut ordered_carts: Vec<(Location, Cart)> = Vec::with_capacity(carts.len());
+        let mut removed: HashSet<Location> = HashSet::with_capacity(carts.len());
+        loop {
+            if carts.len() == 1 {
+                let location = *carts.keys().next().unwrap();
+                break format!("X: {}, Y: {}", location.column.0, location.row.0);
+            }
+
+            // Collect the carts in sorted order
+            ordered_carts.clear();
+            ordered_carts.extend(carts.iter().map(|(loc, cart)| (*loc, *cart)));
+            ordered_carts.sort_by_key(|(loc, _)| (loc.row, loc.column));
+
+            removed.clear();
+
+            for (location, mut cart) in ordered_carts.iter().cloned() {
+                // This cart was removed in a collision; skip it
+                if removed.contains(&location) {
+                    continue;
+                }
+
+                // Move the cart
+                carts.remove(&location);
+                let new_location = location.step(cart.direction);
+
+                // Check for collisions
+                if carts.remove(&new_location).is_some() {
+                    removed.insert(new_location);
+                    continue;
+                }
+
+                // Re-orient the cart
+                match grid.get(new_location).unwrap() {
+                    Track::Empty => panic!("Cart {:?} entered empty track at {:?} from {:?}", cart, new_location, location),
+                    Track::Horizontal | Track::Vertical => {},
+                    Track::LeftBend => match cart.direction {
+                        Up | Down => { cart.direction = cart.direction.anticlockwise(); }
+                        Left | Right => { cart.direction = cart.direction.clockwise(); }
+                    }
+                    Track::RightBend => match cart.direction {
+                        Up | Down => { cart.direction = cart.direction.clockwise(); }
+                        Left | Right => { cart.direction = cart.direction.anticlockwise(); }
+                    }
+                    Track::Intersection => {
+                        match cart.next_turn {
+                            Turn::Straight => {},
+                            Turn::Left => { cart.direction = cart.direction.anticlockwise(); }
+                            Turn::Right => { cart.direction = cart.direction.clockwise(); }
+                        }
+                        cart.next_turn = cart.next_turn.next();
+                    }
+                }
+
+                // Change the cart in the carts table
+                carts.insert(new_location, cart);
+            }
+        }
+    }
+}
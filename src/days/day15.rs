@@ -0,0 +1,52 @@
+#![allow(unused_imports, dead_code)]
+
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::hash::Hash;
+use std::io::{self, Read};
+use std::iter::{self, FromIterator, FusedIterator, Peekable};
+use std::mem::{replace, swap};
+use std::ops::Add;
+use std::process::exit;
+use std::rc::{Rc, Weak};
+use std::str::FromStr;
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+
+use joinery::prelude::*;
+use lazy_static::lazy_static;
+use rayon::prelude::*;
+use regex::{self, Regex};
+use gridly::prelude::*;
+use gridly_grids::*;
+use generations::*;
+
+use crate::error::ParseError;
+use crate::harness::RegexExtractor;
+use crate::solution::{Solution, Unsolved};
+
+// CODE GOES HERE
+
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u32 = 15;
+
+    type Input = ();
+    type Part1 = Unsolved;
+    type Part2 = Unsolved;
+
+    fn parse(_input: &str) -> Result<(), ParseError> {
+        Ok(())
+    }
+
+    fn part1(_input: &()) -> Unsolved {
+        Unsolved
+    }
+
+    fn part2(_input: &()) -> Unsolved {
+        Unsolved
+    }
+}
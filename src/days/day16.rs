@@ -0,0 +1,573 @@
+#![allow(unused_imports, dead_code)]
+
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::hash::Hash;
+use std::io::{self, Read};
+use std::iter::{self, FromIterator, FusedIterator, Peekable};
+use std::mem::{replace, swap};
+use std::ops::Add;
+use std::process::exit;
+use std::rc::{Rc, Weak};
+use std::str::FromStr;
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+
+use gridly::prelude::*;
+use joinery::prelude::*;
+use lazy_static::lazy_static;
+use rayon::prelude::*;
+use regex::{self, Regex};
+
+use crate::error::{self, ParseError};
+use crate::harness::RegexExtractor;
+use crate::solution::Solution;
+
+trait FromCode: Sized {
+    fn from_code(code: usize) -> Option<Self>;
+}
+
+impl FromCode for usize {
+    fn from_code(code: usize) -> Option<Self> {
+        Some(code)
+    }
+}
+
+trait RegFetch {
+    fn get_from(&self, registers: &Registers) -> usize;
+}
+
+impl RegFetch for usize {
+    fn get_from(&self, _reg: &Registers) -> usize {
+        *self
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum RegisterID {
+    A,
+    B,
+    C,
+    D,
+}
+
+impl FromCode for RegisterID {
+    fn from_code(code: usize) -> Option<Self> {
+        use RegisterID::*;
+
+        match code {
+            0 => Some(A),
+            1 => Some(B),
+            2 => Some(C),
+            3 => Some(D),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for RegisterID {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, &'static str> {
+        use RegisterID::*;
+
+        match s {
+            "0" => Ok(A),
+            "1" => Ok(B),
+            "2" => Ok(C),
+            "3" => Ok(D),
+            _ => Err("Invalid register id"),
+        }
+    }
+}
+
+impl RegFetch for RegisterID {
+    fn get_from(&self, registers: &Registers) -> usize {
+        registers.get(*self)
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Instruction {
+    opcode: usize,
+    input_a: usize,
+    input_b: usize,
+    output: RegisterID,
+}
+
+macro_rules! operation_builder {
+    ($this:ident $opcode:ident $($name:ident)*) => {
+        match $opcode {$(
+            OpCode::$name => Some(Operation::$name(Params::from_instruction($this)?)),
+        )*}
+    }
+}
+
+impl Instruction {
+    fn make_operation(&self, opcode: OpCode) -> Option<Operation> {
+        operation_builder! {self opcode
+            Addr Addi
+            Mulr Muli
+            Banr Bani
+            Borr Bori
+            Setr Seti
+            Gtri Gtir Gtrr
+            Eqri Eqir Eqrr
+        }
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = &'static str;
+
+    fn from_str(input: &str) -> Result<Instruction, &'static str> {
+        let mut parts = input.split_whitespace();
+        Ok(Instruction {
+            opcode: parts
+                .next()
+                .ok_or("Incorrect number of operands")?
+                .parse()
+                .map_err(|_| "Failed to parse opcode")?,
+            input_a: parts
+                .next()
+                .ok_or("Incorrect number of operands")?
+                .parse()
+                .map_err(|_| "Failed to parse input_a")?,
+            input_b: parts
+                .next()
+                .ok_or("Incorrect number of operands")?
+                .parse()
+                .map_err(|_| "Failed to parse input_b")?,
+            output: parts
+                .next()
+                .ok_or("Incorrect number of operands")?
+                .parse()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct Params<InputA: RegFetch + FromCode, InputB: RegFetch + FromCode> {
+    input_a: InputA,
+    input_b: InputB,
+    output: RegisterID,
+}
+
+impl<InputA: RegFetch + FromCode, InputB: RegFetch + FromCode> Params<InputA, InputB> {
+    fn from_instruction(instruction: &Instruction) -> Option<Self> {
+        Some(Self {
+            input_a: InputA::from_code(instruction.input_a)?,
+            input_b: InputB::from_code(instruction.input_b)?,
+            output: instruction.output,
+        })
+    }
+
+    fn apply(&self, registers: &mut Registers, op: impl Fn(usize, usize) -> usize) {
+        registers.set(
+            self.output,
+            op(
+                self.input_a.get_from(registers),
+                self.input_b.get_from(registers),
+            ),
+        );
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum OpCode {
+    Addr,
+    Addi,
+
+    Mulr,
+    Muli,
+
+    Banr,
+    Bani,
+
+    Borr,
+    Bori,
+
+    Setr,
+    Seti,
+
+    Gtir,
+    Gtri,
+    Gtrr,
+
+    Eqir,
+    Eqri,
+    Eqrr,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+enum Operation {
+    Addr(Params<RegisterID, RegisterID>),
+    Addi(Params<RegisterID, usize>),
+
+    Mulr(Params<RegisterID, RegisterID>),
+    Muli(Params<RegisterID, usize>),
+
+    Banr(Params<RegisterID, RegisterID>),
+    Bani(Params<RegisterID, usize>),
+
+    Borr(Params<RegisterID, RegisterID>),
+    Bori(Params<RegisterID, usize>),
+
+    Setr(Params<RegisterID, RegisterID>),
+    Seti(Params<usize, RegisterID>),
+
+    Gtir(Params<usize, RegisterID>),
+    Gtri(Params<RegisterID, usize>),
+    Gtrr(Params<RegisterID, RegisterID>),
+
+    Eqir(Params<usize, RegisterID>),
+    Eqri(Params<RegisterID, usize>),
+    Eqrr(Params<RegisterID, RegisterID>),
+}
+
+impl Operation {
+    fn from_instruction(instruction: &Instruction) -> Option<Self> {
+        use Operation::*;
+
+        match instruction.opcode {
+            0 => Some(Gtrr(Params::from_instruction(instruction)?)),
+            1 => Some(Borr(Params::from_instruction(instruction)?)),
+            2 => Some(Gtir(Params::from_instruction(instruction)?)),
+            3 => Some(Eqri(Params::from_instruction(instruction)?)),
+            4 => Some(Addr(Params::from_instruction(instruction)?)),
+            5 => Some(Seti(Params::from_instruction(instruction)?)),
+            6 => Some(Eqrr(Params::from_instruction(instruction)?)),
+            7 => Some(Gtri(Params::from_instruction(instruction)?)),
+            8 => Some(Banr(Params::from_instruction(instruction)?)),
+            9 => Some(Addi(Params::from_instruction(instruction)?)),
+            10 => Some(Setr(Params::from_instruction(instruction)?)),
+            11 => Some(Mulr(Params::from_instruction(instruction)?)),
+            12 => Some(Bori(Params::from_instruction(instruction)?)),
+            13 => Some(Muli(Params::from_instruction(instruction)?)),
+            14 => Some(Eqir(Params::from_instruction(instruction)?)),
+            15 => Some(Bani(Params::from_instruction(instruction)?)),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Default)]
+pub struct Registers(usize, usize, usize, usize);
+
+macro_rules! apply_block {
+    ($this:ident match $input:ident {
+        $(
+            $($Op:ident)* => |$a:ident, $b:ident| $body:expr,
+        )*
+    }) => {
+        match $input {
+            $($(
+                Operation::$Op(params) => params.apply($this, #[inline] |$a, $b| $body),
+            )*)*
+        }
+    }
+}
+
+impl Registers {
+    #[inline]
+    fn get(&self, id: RegisterID) -> usize {
+        match id {
+            RegisterID::A => self.0,
+            RegisterID::B => self.1,
+            RegisterID::C => self.2,
+            RegisterID::D => self.3,
+        }
+    }
+
+    #[inline]
+    fn set(&mut self, id: RegisterID, value: usize) {
+        match id {
+            RegisterID::A => self.0 = value,
+            RegisterID::B => self.1 = value,
+            RegisterID::C => self.2 = value,
+            RegisterID::D => self.3 = value,
+        };
+    }
+
+    fn exec(&mut self, op: &Operation) {
+        apply_block! {
+            self match op {
+                Addr Addi => |a, b| a + b,
+                Mulr Muli => |a, b| a * b,
+                Banr Bani => |a, b| a & b,
+                Borr Bori => |a, b| a | b,
+                Setr Seti => |a, _b| a,
+                Gtri Gtir Gtrr => |a, b| if a > b {1} else {0},
+                Eqri Eqir Eqrr => |a, b| if a == b {1} else {0},
+            }
+        }
+    }
+}
+
+impl FromStr for Registers {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Registers, &'static str> {
+        let mut split = s.split(", ");
+        Ok(Registers(
+            split
+                .next()
+                .ok_or("Incorrect number of register values")?
+                .parse()
+                .map_err(|_| "Invalid register value")?,
+            split
+                .next()
+                .ok_or("Incorrect number of register values")?
+                .parse()
+                .map_err(|_| "Invalid register value")?,
+            split
+                .next()
+                .ok_or("Incorrect number of register values")?
+                .parse()
+                .map_err(|_| "Invalid register value")?,
+            split
+                .next()
+                .ok_or("Incorrect number of register values")?
+                .parse()
+                .map_err(|_| "Invalid register value")?,
+        ))
+    }
+}
+
+/// Opcode numbers run from 0 to 15
+const NUM_OPCODES: usize = 16;
+
+/// Every opcode number starts out as possibly being any operation
+fn initial_opcode_map() -> Vec<HashSet<OpCode>> {
+    use OpCode::*;
+
+    let all_opcodes: HashSet<OpCode> = [
+        Addr, Addi, Mulr, Muli, Banr, Bani, Borr, Bori, Setr, Seti, Gtir, Gtri, Gtrr, Eqir,
+        Eqri, Eqrr,
+    ]
+    .iter()
+    .copied()
+    .collect();
+
+    iter::repeat_with(|| all_opcodes.clone()).take(NUM_OPCODES).collect()
+}
+
+#[derive(Debug, Clone)]
+pub struct Sample {
+    /// The byte offset of the sample in the input
+    offset: usize,
+    before: Registers,
+    instruction: Instruction,
+    after: Registers,
+}
+
+#[derive(Debug, Clone)]
+pub struct Manual {
+    samples: Vec<Sample>,
+    program: Vec<Instruction>,
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u32 = 16;
+
+    type Input = Manual;
+    type Part1 = usize;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Manual, ParseError> {
+        let mut split = input.split("\n\n\n\n");
+        let part1 = split.next().unwrap();
+        let part2 = split
+            .next()
+            .ok_or_else(|| ParseError::new("Missing the test program after the samples"))?;
+
+        lazy_static! {
+            static ref CASE_PATTERN: Regex = Regex::new(
+                r"Before: \[(\d+, \d+, *\d+, \d+)\]
+(\d+ \d+ \d+ \d+)
+After:  \[(\d+, \d+, \d+, \d+)\]",
+            )
+            .expect("Regex syntax error");
+        }
+
+        // part1 is at the start of input, so offsets into it are offsets into
+        // input
+        let samples = CASE_PATTERN
+            .captures_iter(part1)
+            .map(|case| {
+                let instruction: Instruction = case.try_parse(2)?;
+
+                if instruction.opcode >= NUM_OPCODES {
+                    return Err(ParseError::at_offset(
+                        case.get(2).unwrap().start(),
+                        format!("Opcode {} is out of range", instruction.opcode),
+                    ));
+                }
+
+                Ok(Sample {
+                    offset: case.get(0).unwrap().start(),
+                    instruction,
+                    before: case.try_parse(1)?,
+                    after: case.try_parse(3)?,
+                })
+            })
+            .collect::<Result<_, ParseError>>()?;
+
+        let program = part2
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let offset = error::offset_in(input, line);
+                let error = |err| ParseError::at_offset(offset, format!("Invalid instruction {:?}: {}", line, err));
+
+                let instruction = Instruction::from_str(line).map_err(error)?;
+
+                // Part 2 runs the program, so every operand has to be usable
+                if instruction.opcode >= NUM_OPCODES {
+                    Err(error("opcode out of range"))
+                } else if Operation::from_instruction(&instruction).is_none() {
+                    Err(error("register out of range"))
+                } else {
+                    Ok(instruction)
+                }
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Manual { samples, program })
+    }
+
+    fn part1(manual: &Manual) -> usize {
+        let mut opcode_map = initial_opcode_map();
+
+        manual.samples
+            .iter()
+            .filter(|Sample { before, instruction, after, .. }| {
+                let possible_ops = &mut opcode_map[instruction.opcode];
+
+                possible_ops.retain(|opcode| {
+                    let operation = match instruction.make_operation(*opcode) {
+                        Some(op) => op,
+                        None => return false,
+                    };
+                    let mut local_reg = before.clone();
+                    local_reg.exec(&operation);
+                    local_reg == *after
+                });
+
+                possible_ops.len() >= 3
+            })
+            .count()
+    }
+
+    fn part2(manual: &Manual) -> String {
+        let mut registers = Registers::default();
+        manual.program
+            .iter()
+            .map(|inst| Operation::from_instruction(inst).expect("Instructions are checked by parse"))
+            .for_each(|op| registers.exec(&op));
+
+        format!("{:?}", registers)
+    }
+}
+
+/// Pin down the opcode mapping by elimination: narrow each opcode's
+/// possible operations with each sample, and once an opcode has only one
+/// left, rule that operation out for every other opcode. Fails at the
+/// sample where an opcode runs out of possibilities, which means the samples
+/// contradict each other.
+fn deduce_opcodes(samples: &[Sample]) -> Result<Vec<HashSet<OpCode>>, ParseError> {
+    let mut opcode_map = initial_opcode_map();
+
+    for Sample { offset, before, instruction, after } in samples {
+        let contradiction = || {
+            ParseError::at_offset(
+                *offset,
+                format!("Opcode {} has no possible instruction left after this sample", instruction.opcode),
+            )
+        };
+
+        eprintln!("\nTesting:\nBefore: {:?}\nCode: {:?}\nAfter: {:?}", before, instruction, after);
+
+        let confirmed_code = {
+            let possible_ops = &mut opcode_map[instruction.opcode];
+            eprintln!("Possible ops: {:?}", possible_ops);
+
+            if possible_ops.len() <= 1 {
+                continue;
+            }
+
+            possible_ops.retain(|opcode| {
+                let operation = match instruction.make_operation(*opcode) {
+                    Some(op) => op,
+                    None => return false,
+                };
+                let mut local_reg = before.clone();
+                local_reg.exec(&operation);
+                local_reg == *after
+            });
+
+            eprintln!("Remaining ops: {:?}", possible_ops);
+
+            match possible_ops.len() {
+                0 => return Err(contradiction()),
+                1 => possible_ops.iter().copied().next(),
+                _ => None,
+            }
+        };
+
+        if let Some(confirmed_code) = confirmed_code {
+            eprintln!("Confirmed {} is opcode {:?}", instruction.opcode, confirmed_code);
+            for (i, possible_ops) in opcode_map.iter_mut().enumerate() {
+                if i != instruction.opcode {
+                    possible_ops.remove(&confirmed_code);
+                    if possible_ops.is_empty() {
+                        return Err(contradiction());
+                    }
+                }
+            }
+        }
+    }
+
+    eprintln!("Mapping: {:#?}", opcode_map);
+    Ok(opcode_map)
+}
+
+/// A manual, along with the opcode mapping deduced from its samples
+#[derive(Debug, Clone)]
+pub struct Deduced {
+    manual: Manual,
+    opcode_map: Vec<HashSet<OpCode>>,
+}
+
+/// The earlier take on part 1, which tries to pin down the opcode mapping by
+/// elimination. It's run by the `day16backup` binary. The elimination is done
+/// while parsing, so that samples which contradict each other are reported
+/// as malformed input.
+pub struct Day16Backup;
+
+impl Solution for Day16Backup {
+    const DAY: u32 = 16;
+
+    type Input = Deduced;
+    type Part1 = usize;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Deduced, ParseError> {
+        let manual = Day16::parse(input)?;
+        let opcode_map = deduce_opcodes(&manual.samples)?;
+
+        Ok(Deduced { manual, opcode_map })
+    }
+
+    fn part1(deduced: &Deduced) -> usize {
+        deduced.opcode_map.iter().filter(|op| op.len() >= 3).count()
+    }
+
+    fn part2(deduced: &Deduced) -> String {
+        Day16::part2(&deduced.manual)
+    }
+}
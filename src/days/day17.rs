@@ -1,4 +1,4 @@
-#![allow(unused_imports)]
+#![allow(unused_imports, dead_code)]
 
 use std::collections::{HashMap, HashSet};
 use std::error::Error;
@@ -21,119 +21,7 @@ use regex::{self, Regex};
 use gridly::prelude::*;
 use gridly_grids::*;
 
-// DON'T TOUCH THIS
-#[inline(always)]
-fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
-    let start = Instant::now();
-    let result = f();
-    let end = Instant::now();
-    (result, end - start)
-}
-
-trait ReadString: Read {
-    fn read_string(&mut self) -> io::Result<String> {
-        let mut data = String::new();
-        self.read_to_string(&mut data).map(|_| data)
-    }
-}
-
-impl<T: Read> ReadString for T {}
-
-fn main() {
-    let ((), total_duration) = timed(move || {
-        let input = io::stdin().read_string().unwrap_or_else(|err| {
-            eprintln!("Error reading input: {}", err);
-            exit(1);
-        });
-
-        let (solution, duration) = timed(move || solve(&input));
-        println!("{}", solution);
-
-        eprintln!("Algorithm duration: {:?}", duration);
-    });
-    eprintln!("Total duration: {:?}", total_duration);
-}
-
-trait RegexExtractor<'t> {
-    fn field<T>(&self, index: usize) -> T
-    where
-        &'t str: Into<T>;
-
-    fn parse<T: FromStr>(&self, index: usize) -> T
-    where
-        T::Err: Display;
-}
-
-impl<'t> RegexExtractor<'t> for regex::Captures<'t> {
-    #[inline]
-    fn field<T>(&self, index: usize) -> T
-    where
-        &'t str: Into<T>,
-    {
-        self.get(index)
-            .unwrap_or_else(move || panic!("Group {} didn't match anything", index))
-            .as_str()
-            .into()
-    }
-
-    #[inline]
-    fn parse<T: FromStr>(&self, index: usize) -> T
-    where
-        T::Err: Display,
-    {
-        let field: &str = self.field(index);
-
-        field.parse().unwrap_or_else(move |err| {
-            panic!("Failed to parse group {} \"{}\": {}", index, field, err)
-        })
-    }
-}
-
-#[derive(Debug, Clone)]
-struct UniqueIterator<I: Iterator> {
-    last: Option<I::Item>,
-    iter: I,
-}
-
-impl<I: Iterator> Iterator for UniqueIterator<I>
-where
-    I::Item: Clone + PartialEq,
-{
-    type Item = I::Item;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        let next = match &self.last {
-            None => self.iter.next(),
-            Some(last) => self.iter.find(|item| item != last),
-        };
-
-        self.last = next.clone();
-        next
-    }
-
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        let (min, max) = self.iter.size_hint();
-
-        if self.last.is_none() && min > 0 {
-            (1, max)
-        } else {
-            (0, max)
-        }
-    }
-}
-
-impl<I: FusedIterator> FusedIterator for UniqueIterator<I> where I::Item: Clone + PartialEq {}
-
-trait BetterIterator: Iterator + Sized {
-    fn unique(self) -> UniqueIterator<Self> {
-        UniqueIterator {
-            iter: self,
-            last: None,
-        }
-    }
-}
-
-impl<I: Iterator> BetterIterator for I {}
+use crate::harness::RegexExtractor;
 
 // CODE GOES HERE
 
@@ -187,7 +75,7 @@ fn flow_right<G: Grid>(grid: &mut G, loc: Location) -> usize {
 
 
 #[inline(always)]
-fn solve(input: &str) -> impl Display {
+pub fn solve(input: &str) -> impl Display {
     let mut grid = VecGrid::new_fill((2000, 2000), &Empty).unwrap();
 
     let pattern = Regex::new(r"^(:x?
@@ -0,0 +1,132 @@
+#![allow(unused_imports, dead_code)]
+
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::hash::Hash;
+use std::io::{self, Read};
+use std::iter::{self, FromIterator, FusedIterator, Peekable};
+use std::mem::{replace, swap};
+use std::ops::Add;
+use std::process::exit;
+use std::rc::{Rc, Weak};
+use std::str::FromStr;
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+
+use joinery::prelude::*;
+use lazy_static::lazy_static;
+use rayon::prelude::*;
+use regex::{self, Regex};
+use gridly::prelude::*;
+use gridly_grids::*;
+use generations::*;
+use lazy_format::lazy_format;
+
+use crate::error::ParseError;
+use crate::harness::RegexExtractor;
+use crate::solution::{Solution, Unsolved};
+
+// CODE GOES HERE
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum Acre {
+    #[default]
+    Empty,
+    Tree,
+    Lumberyard,
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u32 = 18;
+
+    type Input = VecGrid<Acre>;
+    type Part1 = usize;
+    type Part2 = Unsolved;
+
+    fn parse(input: &str) -> Result<VecGrid<Acre>, ParseError> {
+        let mut grid: VecGrid<Acre> = VecGrid::new(Rows(50) + Columns(50)).unwrap();
+
+        for (row, line) in (0..50).map(Row).zip(input.lines()) {
+            for (col, c) in (0..50).map(Column).zip(line.trim().chars()) {
+                grid[(row, col)] = match c {
+                    '.' => Acre::Empty,
+                    '|' => Acre::Tree,
+                    '#' => Acre::Lumberyard,
+                    _ => return Err(ParseError::at(
+                        row.0 as usize + 1,
+                        col.0 as usize + 1,
+                        format!("Unrecognized character: {:?}", c),
+                    )),
+                };
+            }
+        }
+
+        Ok(grid)
+    }
+
+    fn part1(grid: &VecGrid<Acre>) -> usize {
+        let gens = Generations::new(grid.clone(), grid.clone());
+        let mut sim = gens.with_rule(move |current_gen, next_gen| {
+            current_gen.row_range().cross(current_gen.column_range()).for_each(move |loc| {
+                use Acre::*;
+
+                let adjacent_cells = TOUCHING_ADJACENCIES.iter().filter_map(|v| current_gen.get(loc + v).ok());
+
+                next_gen[loc] = match current_gen[loc] {
+                    Empty => if adjacent_cells.filter(|&&c| c == Tree).count() >= 3 {Tree} else {Empty},
+                    Tree => if adjacent_cells.filter(|&&c| c == Lumberyard).count() >= 3 { Lumberyard} else {Tree},
+                    Lumberyard => {
+                        let mut tree = false;
+                        let mut lumber = false;
+                        for cell in adjacent_cells {
+                            match cell {
+                                Lumberyard => lumber = true,
+                                Tree => tree = true,
+                                _ => {}
+                            };
+                        }
+                        if tree && lumber {Lumberyard} else {Empty}
+                    }
+                };
+            });
+        });
+
+        for i in 0..1000000000 {
+            sim.step();
+
+            if i % 100 == 0 {
+                let current = sim.current();
+                let print = lazy_format!("{row}\n" for row in current.rows().iter().map(|row|
+                    lazy_format!("{cell}" for cell in row.iter().map(|c| match c {
+                        Acre::Lumberyard => "#",
+                        Acre::Tree => "|",
+                        Acre::Empty => ".",
+                    }))));
+
+                eprintln!("{}", print);
+            }
+        }
+
+        let mut trees = 0;
+        let mut lumber = 0;
+
+        for row in sim.current().rows().iter() {
+            for cell in row.iter() {
+                match cell {
+                    Acre::Tree => trees += 1,
+                    Acre::Lumberyard => lumber += 1,
+                    Acre::Empty => {},
+                }
+            }
+        }
+
+        trees * lumber
+    }
+
+    fn part2(_grid: &VecGrid<Acre>) -> Unsolved {
+        Unsolved
+    }
+}
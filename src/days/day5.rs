@@ -0,0 +1,289 @@
+#![allow(unused_imports, dead_code)]
+
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::io::{self, Read, Write};
+use std::iter::{FromIterator, Peekable};
+use std::mem::replace;
+use std::ops::Add;
+use std::process::exit;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use joinery::prelude::*;
+use lazy_static::lazy_static;
+use rayon::prelude::*;
+use regex::{self, Regex};
+
+use crate::error::ParseError;
+use crate::geom::{Bounds, Point};
+use crate::harness::{self, FromCaptures, RegexExtractor};
+use crate::iter::BetterIterator;
+use crate::render::{self, Rgb};
+use crate::solution::Solution;
+
+// CODE GOES HERE
+/// Which seed a cell belongs to in a `Voronoi` diagram
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Owner {
+    /// The cell is strictly closer to this seed (by index) than any other
+    Seed(usize),
+
+    /// Two or more seeds are tied for closest
+    Tie,
+}
+
+/// A Manhattan-distance Voronoi diagram of a set of seeds, computed over
+/// their bounding box.
+///
+/// Outside the bounding box, stepping away from it moves every seed 1 step
+/// further away, so each cell there has the same owner as the nearest cell on
+/// the border. A region that reaches the border is therefore infinite, and
+/// every other region lies entirely inside the box.
+#[derive(Debug, Clone)]
+pub struct Voronoi {
+    seeds: Vec<Point>,
+    bounds: Bounds,
+
+    /// The owner of each cell in `bounds`, in reading order
+    cells: Vec<Owner>,
+
+    /// The number of cells owned by each seed
+    areas: Vec<usize>,
+
+    /// Whether each seed's region reaches the border, and so is infinite
+    infinite: Vec<bool>,
+}
+
+impl Voronoi {
+    /// Build the diagram for a set of seeds, or `None` if there are none
+    pub fn new(seeds: &[Point]) -> Option<Self> {
+        let bounds = Bounds::of(seeds.iter().cloned())?;
+
+        let mut areas = vec![0; seeds.len()];
+        let mut infinite = vec![false; seeds.len()];
+
+        let cells = bounds
+            .points()
+            .map(|cell| {
+                let owner = nearest_seed(seeds, cell);
+
+                if let Owner::Seed(seed) = owner {
+                    areas[seed] += 1;
+                    if bounds.on_border(cell) {
+                        infinite[seed] = true;
+                    }
+                }
+
+                owner
+            })
+            .collect();
+
+        Some(Voronoi {
+            seeds: seeds.to_vec(),
+            bounds,
+            cells,
+            areas,
+            infinite,
+        })
+    }
+
+    pub fn seeds(&self) -> &[Point] {
+        &self.seeds
+    }
+
+    pub fn bounds(&self) -> Bounds {
+        self.bounds
+    }
+
+    /// The owner of a cell, or `None` if it's outside the bounding box
+    pub fn owner(&self, cell: Point) -> Option<Owner> {
+        if !self.bounds.contains(cell) {
+            return None;
+        }
+
+        let offset = cell - self.bounds.min;
+        Some(self.cells[(offset.y * self.bounds.width() + offset.x) as usize])
+    }
+
+    /// The area of a seed's region, or `None` if it's infinite
+    pub fn area(&self, seed: usize) -> Option<usize> {
+        if self.infinite[seed] {
+            None
+        } else {
+            Some(self.areas[seed])
+        }
+    }
+
+    pub fn is_infinite(&self, seed: usize) -> bool {
+        self.infinite[seed]
+    }
+
+    /// The area of the largest finite region, if there are any
+    pub fn largest_finite_area(&self) -> Option<usize> {
+        (0..self.areas.len()).filter_map(|seed| self.area(seed)).max()
+    }
+
+    /// Draw the diagram as text. Each cell shows the label of the seed that
+    /// owns it: uppercase for finite regions and lowercase for infinite ones,
+    /// so labels repeat after 26 seeds. Seeds are `#` and ties are `.`. A
+    /// legend of the seeds, with their areas, follows the map.
+    pub fn render_ascii(&self) -> String {
+        let mut out = render::ascii(&self.bounds, |cell| match self.owner(cell).unwrap() {
+            Owner::Seed(seed) if self.seeds[seed] == cell => '#',
+            Owner::Seed(seed) => self.label(seed),
+            Owner::Tie => '.',
+        });
+
+        for (index, seed) in self.seeds.iter().enumerate() {
+            out.push_str(&match self.area(index) {
+                Some(area) => format!("\n{} ({}): area {}", self.label(index), seed, area),
+                None => format!("\n{} ({}): infinite", self.label(index), seed),
+            });
+        }
+
+        out
+    }
+
+    fn label(&self, seed: usize) -> char {
+        let label = render::label(seed % 26);
+        if self.infinite[seed] { label.to_ascii_lowercase() } else { label }
+    }
+
+    /// The colour of a cell in the diagram. Each seed's region gets its own
+    /// colour, dimmed if it's infinite; seeds are white and ties are black.
+    /// Cells outside the bounds are black.
+    pub fn colour(&self, cell: Point) -> Rgb {
+        match self.owner(cell) {
+            Some(Owner::Seed(seed)) if self.seeds[seed] == cell => Rgb::WHITE,
+            Some(Owner::Seed(seed)) if self.infinite[seed] => render::palette(seed).dim(),
+            Some(Owner::Seed(seed)) => render::palette(seed),
+            Some(Owner::Tie) | None => Rgb::BLACK,
+        }
+    }
+
+    /// Draw the diagram as a PPM image, one pixel per cell, coloured with
+    /// `colour`
+    pub fn write_ppm(&self, out: impl Write) -> io::Result<()> {
+        render::write_ppm(out, &self.bounds, |cell| self.colour(cell))
+    }
+}
+
+fn nearest_seed(seeds: &[Point], cell: Point) -> Owner {
+    let mut best = Owner::Tie;
+    let mut best_distance = i64::MAX;
+
+    for (index, seed) in seeds.iter().enumerate() {
+        let distance = seed.manhattan(cell);
+
+        if distance < best_distance {
+            best = Owner::Seed(index);
+            best_distance = distance;
+        } else if distance == best_distance {
+            best = Owner::Tie;
+        }
+    }
+
+    best
+}
+
+/// The sum of the distances along one axis from every coordinate to each
+/// position within `margin` of their range, in order.
+fn axis_distances(mut coords: Vec<i64>, margin: i64) -> Vec<i64> {
+    coords.sort_unstable();
+
+    let low = coords[0] - margin;
+    let high = coords[coords.len() - 1] + margin;
+    let count = coords.len() as i64;
+
+    let mut distance: i64 = coords.iter().map(|coord| coord - low).sum();
+    let mut below = 0;
+    let mut distances = Vec::with_capacity((high - low + 1) as usize);
+
+    for position in low..=high {
+        distances.push(distance);
+
+        // Stepping right moves 1 step away from every coordinate at or below
+        // this position, and 1 step closer to every one above it
+        while below < coords.len() && coords[below] <= position {
+            below += 1;
+        }
+        distance += below as i64 - (count - below as i64);
+    }
+
+    distances
+}
+
+/// The number of cells whose total Manhattan distance to every coordinate is
+/// less than `threshold`.
+///
+/// The total distance is the sum of an x part and a y part, which are
+/// computed separately. A cell `d` steps outside the coordinates' range on
+/// one axis is at least `d` away from every coordinate, so only positions
+/// within `threshold / n` of the range can count.
+pub fn safe_region_size(coords: &[Point], threshold: i64) -> usize {
+    if coords.is_empty() || threshold <= 0 {
+        return 0;
+    }
+
+    let margin = threshold / coords.len() as i64;
+
+    let x_distances = axis_distances(coords.iter().map(|coord| coord.x).collect(), margin);
+    let mut y_distances = axis_distances(coords.iter().map(|coord| coord.y).collect(), margin);
+    y_distances.sort_unstable();
+
+    x_distances
+        .iter()
+        .map(|x_distance| y_distances.partition_point(|y_distance| x_distance + y_distance < threshold))
+        .sum()
+}
+
+const SAFE_THRESHOLD: i64 = 10000;
+
+/// The answer to part 1: the area of the largest finite region, if any
+/// region is finite. With a single seed, or seeds all in one row, every region
+/// reaches the border.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LargestArea(pub Option<usize>);
+
+impl Display for LargestArea {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.0 {
+            Some(area) => area.fmt(f),
+            None => "no finite region".fmt(f),
+        }
+    }
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u32 = 5;
+
+    type Input = Vec<Point>;
+    type Part1 = LargestArea;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Point>, ParseError> {
+        lazy_static! {
+            static ref PATTERN: Regex = Regex::new(r"(?P<x>\d+), (?P<y>\d+)").unwrap();
+        }
+
+        let coords = harness::parse_lines(input, &PATTERN)?;
+
+        if coords.is_empty() {
+            return Err(ParseError::new("There are no coordinates"));
+        }
+
+        Ok(coords)
+    }
+
+    fn part1(coords: &Vec<Point>) -> LargestArea {
+        LargestArea(Voronoi::new(coords).expect("parse rejects empty input").largest_finite_area())
+    }
+
+    fn part2(coords: &Vec<Point>) -> usize {
+        safe_region_size(coords, SAFE_THRESHOLD)
+    }
+}
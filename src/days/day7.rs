@@ -0,0 +1,244 @@
+#![allow(unused_imports, dead_code)]
+
+use std::cmp::Reverse;
+use std::collections::{BTreeSet, BinaryHeap, HashMap, HashSet};
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::io::{self, Read};
+use std::iter::{FromIterator, Peekable};
+use std::mem::replace;
+use std::ops::Add;
+use std::process::exit;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use joinery::prelude::*;
+use lazy_static::lazy_static;
+use rayon::prelude::*;
+use regex::{self, Regex};
+
+use crate::error::ParseError;
+use crate::from_captures;
+use crate::graph::DepGraph;
+use crate::harness::{self, FromCaptures, RegexExtractor};
+use crate::solution::Solution;
+
+// CODE GOES HERE
+
+from_captures! {
+    /// A single "Step X must be finished before step Y" instruction
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct Dependency {
+        prereq: char,
+        step: char,
+    }
+}
+
+/// One step being worked on, in a `Schedule`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Task {
+    pub step: char,
+    pub worker: usize,
+    pub start: u32,
+    pub end: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventKind {
+    Start,
+    Finish,
+}
+
+/// A worker starting or finishing a step
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Event {
+    pub time: u32,
+    pub worker: usize,
+    pub step: char,
+    pub kind: EventKind,
+}
+
+/// The result of simulating a pool of workers completing the steps
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Schedule {
+    pub workers: usize,
+
+    /// Every step, in the order they were started
+    pub tasks: Vec<Task>,
+
+    /// The time when the last step finished
+    pub total_time: u32,
+}
+
+impl Schedule {
+    /// Simulate `workers` workers completing the steps. Whenever a worker is
+    /// idle, it takes the alphabetically first step whose prerequisites are
+    /// all finished; idle workers are assigned in order.
+    pub fn run(steps: &DepGraph<char>, workers: usize, duration: impl Fn(char) -> u32) -> Schedule {
+        assert!(workers > 0, "The schedule needs at least one worker");
+
+        // Number of unfinished prerequisites of each step
+        let mut waiting_on: HashMap<char, usize> =
+            steps.steps().map(|&step| (step, steps.prereqs(&step).count())).collect();
+
+        let mut available: BinaryHeap<Reverse<char>> = waiting_on
+            .iter()
+            .filter(|(_, &count)| count == 0)
+            .map(|(&step, _)| Reverse(step))
+            .collect();
+
+        // (end time, worker, step) for each task in progress
+        let mut in_progress: BinaryHeap<Reverse<(u32, usize, char)>> = BinaryHeap::new();
+        let mut idle: BTreeSet<usize> = (0..workers).collect();
+        let mut tasks = Vec::with_capacity(steps.len());
+        let mut time = 0;
+
+        loop {
+            while let Some(&worker) = idle.iter().next() {
+                let Reverse(step) = match available.pop() {
+                    Some(step) => step,
+                    None => break,
+                };
+
+                idle.remove(&worker);
+                let end = time + duration(step);
+                tasks.push(Task { step, worker, start: time, end });
+                in_progress.push(Reverse((end, worker, step)));
+            }
+
+            let Reverse((end, _, _)) = match in_progress.peek() {
+                Some(&next) => next,
+                None => break,
+            };
+            time = end;
+
+            // Finish everything that ends now before assigning new work
+            while let Some(&Reverse((end, worker, step))) = in_progress.peek() {
+                if end != time {
+                    break;
+                }
+
+                in_progress.pop();
+                idle.insert(worker);
+
+                for &next in steps.dependents(&step) {
+                    let count = waiting_on.get_mut(&next).unwrap();
+                    *count -= 1;
+                    if *count == 0 {
+                        available.push(Reverse(next));
+                    }
+                }
+            }
+        }
+
+        Schedule { workers, tasks, total_time: time }
+    }
+
+    /// Every start and finish, in time order. At any given time, finishes
+    /// come before starts.
+    pub fn events(&self) -> Vec<Event> {
+        let mut events: Vec<Event> = self
+            .tasks
+            .iter()
+            .flat_map(|task| {
+                let event = move |time, kind| Event { time, worker: task.worker, step: task.step, kind };
+                vec![event(task.start, EventKind::Start), event(task.end, EventKind::Finish)]
+            })
+            .collect();
+
+        events.sort_by_key(|event| (event.time, event.kind == EventKind::Start, event.worker));
+        events
+    }
+
+    /// The chain of tasks that determined the total time, in order. Working
+    /// back from the last task to finish, each task's predecessor is the
+    /// one whose finish let it start: a prerequisite if one finished right
+    /// then, or otherwise the task that freed up its worker.
+    pub fn critical_path(&self, steps: &DepGraph<char>) -> Vec<char> {
+        let mut current = match self.tasks.iter().max_by_key(|task| task.end) {
+            Some(task) => task,
+            None => return Vec::new(),
+        };
+        let mut path = vec![current.step];
+
+        while current.start > 0 {
+            let finished_then = || self.tasks.iter().filter(|task| task.end == current.start);
+
+            let previous = finished_then()
+                .find(|task| steps.prereqs(&current.step).any(|&prereq| prereq == task.step))
+                .or_else(|| finished_then().find(|task| task.worker == current.worker))
+                .or_else(|| finished_then().next());
+
+            match previous {
+                Some(task) => {
+                    current = task;
+                    path.push(current.step);
+                }
+                None => break,
+            }
+        }
+
+        path.reverse();
+        path
+    }
+
+    /// Draw a Gantt chart: one row per worker, one column per second, with
+    /// the step being worked on or `.` when idle.
+    pub fn gantt(&self) -> String {
+        let mut rows = vec![vec!['.'; self.total_time as usize]; self.workers];
+
+        for task in &self.tasks {
+            rows[task.worker][task.start as usize..task.end as usize]
+                .iter_mut()
+                .for_each(|cell| *cell = task.step);
+        }
+
+        rows.iter()
+            .enumerate()
+            .map(|(worker, row)| format!("Worker {}: {}\n", worker + 1, row.iter().collect::<String>()))
+            .collect()
+    }
+}
+
+/// The puzzle's duration model: 60 seconds, plus 1 for A, 2 for B, and so on
+pub fn default_duration(step: char) -> u32 {
+    60 + (step as u32 - 'A' as u32 + 1)
+}
+
+pub const WORKERS: usize = 5;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u32 = 7;
+
+    type Input = DepGraph<char>;
+    type Part1 = String;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<DepGraph<char>, ParseError> {
+        lazy_static! {
+            static ref PATTERN: Regex =
+                Regex::new(r"(?P<prereq>[A-Z]) must be finished before step (?P<step>[A-Z])").unwrap();
+        }
+
+        let mut steps = DepGraph::new();
+
+        for Dependency { prereq, step } in harness::parse_lines(input, &PATTERN)? {
+            steps.add_dependency(prereq, step);
+        }
+
+        // Reject cycles up front, so that every step can be ordered
+        steps.lexicographic_order().map_err(|cycle| ParseError::new(cycle.to_string()))?;
+
+        Ok(steps)
+    }
+
+    fn part1(steps: &DepGraph<char>) -> String {
+        steps.lexicographic_order().unwrap().into_iter().collect()
+    }
+
+    fn part2(steps: &DepGraph<char>) -> u32 {
+        Schedule::run(steps, WORKERS, default_duration).total_time
+    }
+}
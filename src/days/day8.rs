@@ -0,0 +1,539 @@
+#![allow(unused_imports, dead_code)]
+
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::io::{self, Read};
+use std::iter::{FromIterator, Peekable, self};
+use std::mem::{self, replace};
+use std::ops::{Add, Range};
+use std::process::exit;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use joinery::prelude::*;
+use lazy_static::lazy_static;
+use rayon::prelude::*;
+use regex::{self, Regex};
+
+use crate::error::{self, ParseError};
+use crate::harness::RegexExtractor;
+use crate::solution::Solution;
+
+// CODE GOES HERE
+
+/// Why a stream of numbers isn't a valid tree. Positions are token indexes
+/// into the stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TreeError {
+    /// The input ended in the middle of the header starting at `token`
+    ShortHeader { token: usize },
+
+    /// The node whose header is at `node` needs `expected` metadata entries
+    /// starting at `token`, but the input ended after `found` of them
+    ShortMetadata {
+        node: usize,
+        token: usize,
+        expected: usize,
+        found: usize,
+    },
+
+    /// There's more input after the root node ended at `token`
+    TrailingData { token: usize },
+}
+
+impl TreeError {
+    /// The token where the problem was found
+    pub fn token(&self) -> usize {
+        match *self {
+            TreeError::ShortHeader { token } => token,
+            TreeError::ShortMetadata { token, found, .. } => token + found,
+            TreeError::TrailingData { token } => token,
+        }
+    }
+}
+
+impl Display for TreeError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match *self {
+            TreeError::ShortHeader { token } => {
+                write!(f, "Input ended in the middle of the node header at token {}", token)
+            }
+            TreeError::ShortMetadata { node, token, expected, found } => write!(
+                f,
+                "Node at token {} needs {} metadata entries from token {}, but the input ended after {}",
+                node, expected, token, found
+            ),
+            TreeError::TrailingData { token } => {
+                write!(f, "Unexpected data after the root node, at token {}", token)
+            }
+        }
+    }
+}
+
+impl Error for TreeError {}
+
+#[derive(Debug, Clone, Default)]
+pub struct Node {
+    children: Vec<Node>,
+    meta: Vec<usize>,
+}
+
+/// A node whose header has been read, but whose children haven't all been
+/// built yet. `C` is however the builder refers to finished children.
+struct PartialNode<C> {
+    header: usize,
+    num_children: usize,
+    num_meta: usize,
+    children: Vec<C>,
+}
+
+impl<C> PartialNode<C> {
+    /// Read the header at `position`, and advance past it
+    fn read(numbers: &[usize], position: &mut usize) -> Result<Self, TreeError> {
+        match numbers.get(*position..*position + 2) {
+            Some(&[num_children, num_meta]) => {
+                let node = PartialNode {
+                    header: *position,
+                    num_children,
+                    num_meta,
+                    children: Vec::new(),
+                };
+                *position += 2;
+                Ok(node)
+            }
+            _ => Err(TreeError::ShortHeader { token: *position }),
+        }
+    }
+
+    fn is_complete(&self) -> bool {
+        self.children.len() >= self.num_children
+    }
+
+    /// Read this node's metadata at `position`, and advance past it
+    fn read_meta<'a>(&self, numbers: &'a [usize], position: &mut usize) -> Result<&'a [usize], TreeError> {
+        let start = *position;
+
+        match start.checked_add(self.num_meta).and_then(|end| numbers.get(start..end)) {
+            Some(meta) => {
+                *position += meta.len();
+                Ok(meta)
+            }
+            None => Err(TreeError::ShortMetadata {
+                node: self.header,
+                token: start,
+                expected: self.num_meta,
+                found: numbers.len() - start,
+            }),
+        }
+    }
+}
+
+/// Split the input into numbers, along with the byte offset of each
+fn tokenize(input: &str) -> Result<(Vec<usize>, Vec<usize>), ParseError> {
+    input
+        .split_whitespace()
+        .enumerate()
+        .map(|(i, token)| {
+            let offset = error::offset_in(input, token);
+
+            match token.parse::<usize>() {
+                Ok(number) => Ok((number, offset)),
+                Err(err) => Err(ParseError::at_offset(
+                    offset,
+                    format!("Invalid number {:?} at token {}: {}", token, i, err),
+                )),
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map(|tokens| tokens.into_iter().unzip())
+}
+
+/// Parse the input as a tree with `build`, converting token positions in
+/// errors into byte offsets
+fn parse_tree<T>(input: &str, build: impl FnOnce(&[usize]) -> Result<T, TreeError>) -> Result<T, ParseError> {
+    let (numbers, offsets) = tokenize(input)?;
+
+    build(&numbers).map_err(|err| {
+        let offset = offsets.get(err.token()).cloned().unwrap_or(input.len());
+        ParseError::at_offset(offset, err.to_string())
+    })
+}
+
+/// Add up metadata entries or node values. Values can grow exponentially
+/// with depth, since a node can refer to the same child many times, so sums
+/// saturate at `usize::MAX` rather than overflowing.
+fn saturating_sum(items: impl IntoIterator<Item = usize>) -> usize {
+    items.into_iter().fold(0, usize::saturating_add)
+}
+
+impl Node {
+    /// Make a node directly, for hand-crafting trees
+    pub fn new(children: Vec<Node>, meta: Vec<usize>) -> Node {
+        Node { children, meta }
+    }
+
+    pub fn children(&self) -> &[Node] {
+        &self.children
+    }
+
+    pub fn meta(&self) -> &[usize] {
+        &self.meta
+    }
+
+    /// Build a tree from the flat stream of headers and metadata. This uses
+    /// an explicit stack rather than recursion, so it can handle trees of
+    /// any depth.
+    pub fn build(numbers: &[usize]) -> Result<Node, TreeError> {
+        let mut position = 0;
+        let mut stack: Vec<PartialNode<Node>> = vec![PartialNode::read(numbers, &mut position)?];
+
+        loop {
+            let top = stack.last_mut().unwrap();
+
+            if !top.is_complete() {
+                let child = PartialNode::read(numbers, &mut position)?;
+                stack.push(child);
+                continue;
+            }
+
+            let meta = top.read_meta(numbers, &mut position)?.to_vec();
+            let children = stack.pop().unwrap().children;
+            let node = Node { children, meta };
+
+            match stack.last_mut() {
+                Some(parent) => parent.children.push(node),
+                None if position < numbers.len() => return Err(TreeError::TrailingData { token: position }),
+                None => break Ok(node),
+            }
+        }
+    }
+
+    /// The sum of the metadata of this node and all of its descendants
+    pub fn meta_score(&self) -> usize {
+        let mut stack = vec![self];
+        let mut total: usize = 0;
+
+        while let Some(node) = stack.pop() {
+            total = total.saturating_add(saturating_sum(node.meta.iter().copied()));
+            stack.extend(&node.children);
+        }
+
+        total
+    }
+
+    /// The value of a node is the sum of its metadata if it has no children,
+    /// or else the sum of the values of the children its metadata refers to
+    /// (1-indexed, possibly more than once).
+    ///
+    /// This is evaluated bottom-up, in post-order, with an explicit stack of
+    /// nodes and the values of their children finished so far. Values
+    /// saturate at `usize::MAX` rather than overflowing.
+    pub fn value(&self) -> usize {
+        let mut stack: Vec<(&Node, Vec<usize>)> = vec![(self, Vec::new())];
+
+        loop {
+            let top = stack.last_mut().unwrap();
+            let node = top.0;
+
+            if let Some(child) = node.children.get(top.1.len()) {
+                stack.push((child, Vec::with_capacity(child.children.len())));
+                continue;
+            }
+
+            let (_, values) = stack.pop().unwrap();
+            let value = if node.children.is_empty() {
+                saturating_sum(node.meta.iter().copied())
+            } else {
+                saturating_sum(node.meta.iter().filter_map(|&meta| values.get(meta.checked_sub(1)?).copied()))
+            };
+
+            match stack.last_mut() {
+                Some((_, parent_values)) => parent_values.push(value),
+                None => break value,
+            }
+        }
+    }
+}
+
+impl Node {
+    /// The flat stream of headers and metadata for this tree; the inverse of
+    /// `Node::build`
+    pub fn numbers(&self) -> Vec<usize> {
+        enum Visit<'a> {
+            Enter(&'a Node),
+            Exit(&'a Node),
+        }
+
+        let mut numbers = Vec::new();
+        let mut stack = vec![Visit::Enter(self)];
+
+        while let Some(visit) = stack.pop() {
+            match visit {
+                Visit::Enter(node) => {
+                    numbers.push(node.children.len());
+                    numbers.push(node.meta.len());
+                    stack.push(Visit::Exit(node));
+                    stack.extend(node.children.iter().rev().map(Visit::Enter));
+                }
+                Visit::Exit(node) => numbers.extend(&node.meta),
+            }
+        }
+
+        numbers
+    }
+
+    /// Every node in pre-order
+    fn preorder(&self) -> Vec<Visited<'_>> {
+        let mut nodes = Vec::new();
+        let mut stack = vec![Visited { node: self, depth: 0, parent: None }];
+
+        while let Some(visited) = stack.pop() {
+            let index = nodes.len();
+            let Visited { node, depth, .. } = visited;
+            nodes.push(visited);
+
+            stack.extend(node.children.iter().enumerate().rev().map(|(ordinal, child)| Visited {
+                node: child,
+                depth: depth + 1,
+                parent: Some((index, ordinal)),
+            }));
+        }
+
+        nodes
+    }
+
+    /// Show the tree with one node per line, indented by depth, along with
+    /// each node's metadata and value. Use the returned value's `Display`
+    /// implementation to get the text.
+    pub fn pretty(&self) -> Pretty<'_> {
+        Pretty { root: self }
+    }
+}
+
+/// The flat, space-separated format that `Day8::parse` reads
+impl Display for Node {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.numbers().iter().join_with(' '))
+    }
+}
+
+/// A node reached by `Node::preorder`, with its depth, its parent's index in
+/// the pre-order list, and its own index among its parent's children
+struct Visited<'a> {
+    node: &'a Node,
+    depth: usize,
+    parent: Option<(usize, usize)>,
+}
+
+/// An indented view of a `Node` tree. Created by `Node::pretty`.
+#[derive(Debug, Clone, Copy)]
+pub struct Pretty<'a> {
+    root: &'a Node,
+}
+
+impl<'a> Display for Pretty<'a> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let nodes = self.root.preorder();
+
+        // In reverse pre-order every node comes after all of its descendants,
+        // so each child's value is final by the time it's added to its parent
+        let mut values: Vec<usize> = nodes
+            .iter()
+            .map(|visited| {
+                if visited.node.children.is_empty() {
+                    saturating_sum(visited.node.meta.iter().copied())
+                } else {
+                    0
+                }
+            })
+            .collect();
+
+        for (index, visited) in nodes.iter().enumerate().rev() {
+            if let Some((parent, ordinal)) = visited.parent {
+                let references = nodes[parent].node.meta.iter().filter(|&&meta| meta == ordinal + 1).count();
+                values[parent] = values[parent].saturating_add(references.saturating_mul(values[index]));
+            }
+        }
+
+        for (visited, value) in nodes.iter().zip(values) {
+            for _ in 0..visited.depth {
+                f.write_str("  ")?;
+            }
+            writeln!(f, "meta [{}], value {}", visited.node.meta.iter().join_with(", "), value)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The default drop would recurse through every level of the tree; this
+/// flattens it so that deep trees can't overflow the stack.
+impl Drop for Node {
+    fn drop(&mut self) {
+        let mut stack = mem::take(&mut self.children);
+
+        while let Some(mut node) = stack.pop() {
+            stack.append(&mut node.children);
+        }
+    }
+}
+
+/// A node in a `Tree`: ranges into the tree's shared buffers
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ArenaNode {
+    children: Range<usize>,
+    meta: Range<usize>,
+}
+
+/// The same tree as `Node`, stored flat: every node's metadata lives in one
+/// shared buffer, and every node's list of children in another. Nodes are
+/// numbered in post-order, so each node's children come before it and the
+/// root is last.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Tree {
+    nodes: Vec<ArenaNode>,
+    children: Vec<usize>,
+    meta: Vec<usize>,
+}
+
+impl Tree {
+    /// Build a tree from the flat stream of headers and metadata, reporting
+    /// errors the same way as `Node::build`.
+    pub fn build(numbers: &[usize]) -> Result<Tree, TreeError> {
+        let mut tree = Tree::default();
+        let mut position = 0;
+        let mut stack: Vec<PartialNode<usize>> = vec![PartialNode::read(numbers, &mut position)?];
+
+        loop {
+            let top = stack.last_mut().unwrap();
+
+            if !top.is_complete() {
+                let child = PartialNode::read(numbers, &mut position)?;
+                stack.push(child);
+                continue;
+            }
+
+            let meta = top.read_meta(numbers, &mut position)?;
+
+            let children_start = tree.children.len();
+            tree.children.append(&mut top.children);
+            let meta_start = tree.meta.len();
+            tree.meta.extend_from_slice(meta);
+
+            let id = tree.nodes.len();
+            tree.nodes.push(ArenaNode {
+                children: children_start..tree.children.len(),
+                meta: meta_start..tree.meta.len(),
+            });
+            stack.pop();
+
+            match stack.last_mut() {
+                Some(parent) => parent.children.push(id),
+                None if position < numbers.len() => return Err(TreeError::TrailingData { token: position }),
+                None => break Ok(tree),
+            }
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// The id of the root node
+    pub fn root(&self) -> usize {
+        self.nodes.len() - 1
+    }
+
+    pub fn children(&self, node: usize) -> &[usize] {
+        &self.children[self.nodes[node].children.clone()]
+    }
+
+    pub fn meta(&self, node: usize) -> &[usize] {
+        &self.meta[self.nodes[node].meta.clone()]
+    }
+
+    /// The sum of all of the metadata in the tree
+    pub fn meta_score(&self) -> usize {
+        saturating_sum(self.meta.iter().copied())
+    }
+
+    /// The value (as in `Node::value`) of every node, by id. Because children
+    /// come before their parents, each node's value is computed exactly once,
+    /// from its children's already-computed values. Like `Node::value`, values
+    /// saturate at `usize::MAX`.
+    pub fn values(&self) -> Vec<usize> {
+        let mut values = Vec::with_capacity(self.nodes.len());
+
+        for id in 0..self.nodes.len() {
+            let children = self.children(id);
+            let meta = self.meta(id).iter();
+
+            let value = if children.is_empty() {
+                saturating_sum(meta.copied())
+            } else {
+                let referenced = meta.filter_map(|&meta| children.get(meta.checked_sub(1)?));
+                saturating_sum(referenced.map(|&child| values[child]))
+            };
+
+            values.push(value);
+        }
+
+        values
+    }
+
+    /// The value of the root node
+    pub fn value(&self) -> usize {
+        self.values()[self.root()]
+    }
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u32 = 8;
+
+    type Input = Node;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Node, ParseError> {
+        parse_tree(input, Node::build)
+    }
+
+    fn part1(data: &Node) -> usize {
+        data.meta_score()
+    }
+
+    fn part2(data: &Node) -> usize {
+        data.value()
+    }
+}
+
+/// Day 8 on the flat `Tree` rather than `Node`, to compare the two with
+/// `--bench`. It's run by the `day8arenapart1` and `day8arenapart2` binaries.
+pub struct Day8Arena;
+
+impl Solution for Day8Arena {
+    const DAY: u32 = 8;
+
+    type Input = Tree;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Tree, ParseError> {
+        parse_tree(input, Tree::build)
+    }
+
+    fn part1(tree: &Tree) -> usize {
+        tree.meta_score()
+    }
+
+    fn part2(tree: &Tree) -> usize {
+        tree.value()
+    }
+}
@@ -0,0 +1,167 @@
+#![allow(unused_imports, dead_code)]
+
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::io::{self, Read};
+use std::iter::{FromIterator, Peekable};
+use std::mem::replace;
+use std::ops::Add;
+use std::process::exit;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use joinery::prelude::*;
+use lazy_static::lazy_static;
+use rayon::prelude::*;
+use regex::{self, Regex};
+
+use crate::error::ParseError;
+use crate::from_captures;
+use crate::harness::{FromCaptures, RegexExtractor};
+use crate::solution::Solution;
+
+// CODE GOES HERE
+
+from_captures! {
+    /// The game described by "N players; last marble is worth M points"
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Game {
+        pub players: usize,
+        pub last_marble: u32,
+    }
+}
+
+impl Game {
+    /// Play the game and return every player's score. Each player in turn
+    /// places the next marble between the marbles 1 and 2 places clockwise
+    /// of the current one, except that every 23rd marble is instead kept,
+    /// along with the marble 7 places counter-clockwise, which is removed.
+    ///
+    /// The circle is a doubly-linked ring, indexed by marble number, so that
+    /// every move is constant time.
+    pub fn scores(&self) -> Vec<u64> {
+        let size = self.last_marble as usize + 1;
+        let mut scores = vec![0; self.players];
+
+        // The marbles clockwise and counter-clockwise of each marble. Marble
+        // 0 starts out alone, as its own neighbour.
+        let mut clockwise: Vec<u32> = vec![0; size];
+        let mut counter_clockwise: Vec<u32> = vec![0; size];
+        let mut current = 0;
+
+        for (marble, player) in (1..=self.last_marble).zip((0..self.players).cycle()) {
+            if marble % 23 == 0 {
+                let removed = (0..7).fold(current, |marble, _| counter_clockwise[marble as usize]);
+                let before = counter_clockwise[removed as usize];
+                let after = clockwise[removed as usize];
+
+                clockwise[before as usize] = after;
+                counter_clockwise[after as usize] = before;
+                current = after;
+
+                scores[player] += u64::from(marble) + u64::from(removed);
+            } else {
+                let before = clockwise[current as usize];
+                let after = clockwise[before as usize];
+
+                clockwise[before as usize] = marble;
+                counter_clockwise[marble as usize] = before;
+                clockwise[marble as usize] = after;
+                counter_clockwise[after as usize] = marble;
+                current = marble;
+            }
+        }
+
+        scores
+    }
+
+    /// The winning player's score
+    pub fn high_score(&self) -> u64 {
+        self.scores().into_iter().max().unwrap_or(0)
+    }
+}
+
+/// The original C solutions, from `src/day9part1.c` and `src/day9part2.c`,
+/// built by `build.rs`. They're kept to cross-check `Game` until they're
+/// retired.
+pub mod c {
+    use std::os::raw::c_ulong;
+
+    use super::Game;
+
+    extern "C" {
+        fn day9part1_high_score(num_players: c_ulong, num_marbles: c_ulong) -> c_ulong;
+        fn day9part2_high_score(num_players: c_ulong, num_marbles: c_ulong) -> c_ulong;
+    }
+
+    fn args(game: &Game) -> (c_ulong, c_ulong) {
+        // The C code divides by the number of players
+        assert!(game.players > 0, "The C marble game needs at least one player");
+        (game.players as c_ulong, c_ulong::from(game.last_marble))
+    }
+
+    /// The winning score, from `day9part1.c`
+    pub fn part1_high_score(game: &Game) -> u64 {
+        let (players, marbles) = args(game);
+        unsafe { day9part1_high_score(players, marbles) as u64 }
+    }
+
+    /// The winning score, from `day9part2.c`. This is the same code as
+    /// `part1_high_score`; the two files only differed in their constants.
+    pub fn part2_high_score(game: &Game) -> u64 {
+        let (players, marbles) = args(game);
+        unsafe { day9part2_high_score(players, marbles) as u64 }
+    }
+}
+
+/// How many times larger the last marble is in part 2
+const PART2_SCALE: u32 = 100;
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u32 = 9;
+
+    type Input = Game;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Game, ParseError> {
+        lazy_static! {
+            static ref PATTERN: Regex = Regex::new(
+                r"(?P<players>\d+) players; last marble is worth (?P<last_marble>\d+) points"
+            ).unwrap();
+        }
+
+        let caps = PATTERN.captures(input).ok_or_else(|| {
+            ParseError::new("Expected \"N players; last marble is worth M points\"")
+        })?;
+        let game = Game::from_captures(&caps)?;
+
+        if game.players == 0 {
+            let offset = caps.name("players").unwrap().start();
+            return Err(ParseError::at_offset(offset, "There must be at least one player"));
+        }
+
+        if game.last_marble.checked_mul(PART2_SCALE).is_none() {
+            let offset = caps.name("last_marble").unwrap().start();
+            return Err(ParseError::at_offset(
+                offset,
+                format!("The last marble must be at most {} for part 2", u32::MAX / PART2_SCALE),
+            ));
+        }
+
+        Ok(game)
+    }
+
+    fn part1(game: &Game) -> u64 {
+        game.high_score()
+    }
+
+    /// The same game, with a last marble 100 times larger
+    fn part2(game: &Game) -> u64 {
+        let last_marble = game.last_marble * PART2_SCALE;
+        Game { last_marble, ..*game }.high_score()
+    }
+}
@@ -0,0 +1,53 @@
+//! All of the solvers, one module per day. Each module implements `Solution`
+//! for that day, and is listed in `DAYS` so that the `advent` dispatcher can
+//! find it.
+
+use crate::error::ParseError;
+use crate::harness::{self, Options};
+use crate::solution::{Part, Solution};
+
+pub mod day5;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+pub mod day10;
+pub mod day11;
+pub mod day13;
+pub mod day15;
+pub mod day16;
+pub mod day18;
+
+// day17.rs is still a work in progress and doesn't compile yet, so it isn't
+// part of the module tree.
+
+/// A type-erased handle to a day's `Solution`
+pub struct Entry {
+    pub day: u32,
+    pub run: fn(Part, &Options),
+    pub solve: fn(Part, &str) -> Result<String, ParseError>,
+}
+
+const fn entry<S: Solution>() -> Entry {
+    Entry {
+        day: S::DAY,
+        run: harness::run::<S>,
+        solve: harness::solve::<S>,
+    }
+}
+
+pub static DAYS: &[Entry] = &[
+    entry::<day5::Day5>(),
+    entry::<day7::Day7>(),
+    entry::<day8::Day8>(),
+    entry::<day9::Day9>(),
+    entry::<day10::Day10>(),
+    entry::<day11::Day11>(),
+    entry::<day13::Day13>(),
+    entry::<day15::Day15>(),
+    entry::<day16::Day16>(),
+    entry::<day18::Day18>(),
+];
+
+pub fn find(day: u32) -> Option<&'static Entry> {
+    DAYS.iter().find(|entry| entry.day == day)
+}
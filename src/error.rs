@@ -0,0 +1,142 @@
+//! The crate-wide error type for malformed puzzle input.
+
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Position {
+    Unknown,
+
+    /// A byte offset into the input, which hasn't been resolved into a line
+    /// and column yet
+    Offset(usize),
+
+    /// 1-indexed line and column
+    LineColumn { line: usize, column: usize },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    message: String,
+    position: Position,
+}
+
+impl ParseError {
+    pub fn new(message: impl Display) -> Self {
+        ParseError {
+            message: message.to_string(),
+            position: Position::Unknown,
+        }
+    }
+
+    /// An error at a byte offset into the input. The harness resolves this
+    /// into a line and column once parsing has failed.
+    pub fn at_offset(offset: usize, message: impl Display) -> Self {
+        ParseError {
+            message: message.to_string(),
+            position: Position::Offset(offset),
+        }
+    }
+
+    /// An error at a 1-indexed line and column.
+    pub fn at(line: usize, column: usize, message: impl Display) -> Self {
+        ParseError {
+            message: message.to_string(),
+            position: Position::LineColumn { line, column },
+        }
+    }
+
+    /// Combine several errors into one, reported at the position of the
+    /// first. Returns `None` if there are no errors.
+    pub fn aggregate(errors: impl IntoIterator<Item = ParseError>) -> Option<Self> {
+        let mut errors = errors.into_iter();
+        let first = errors.next()?;
+        let rest: Vec<ParseError> = errors.collect();
+
+        if rest.is_empty() {
+            return Some(first);
+        }
+
+        let mut message = format!("{} errors: {}", rest.len() + 1, first.message);
+        for err in &rest {
+            message.push_str("; ");
+            message.push_str(&err.message);
+        }
+
+        Some(ParseError { message, ..first })
+    }
+
+    /// Move a byte offset `base` bytes later. This is for errors from parsing
+    /// a slice that starts at `base` in the full input.
+    pub fn offset_by(self, base: usize) -> Self {
+        match self.position {
+            Position::Offset(offset) => ParseError {
+                position: Position::Offset(base + offset),
+                ..self
+            },
+            _ => self,
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// The 1-indexed line and column of the error, if it has been resolved.
+    pub fn line_column(&self) -> Option<(usize, usize)> {
+        match self.position {
+            Position::LineColumn { line, column } => Some((line, column)),
+            _ => None,
+        }
+    }
+
+    /// Resolve a byte offset into a line and column, using the input that was
+    /// being parsed.
+    pub fn locate(self, input: &str) -> Self {
+        match self.position {
+            Position::Offset(offset) => {
+                let before = &input[..offset.min(input.len())];
+                let line = before.matches('\n').count() + 1;
+                let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+                let column = before[line_start..].chars().count() + 1;
+
+                ParseError {
+                    position: Position::LineColumn { line, column },
+                    ..self
+                }
+            }
+            _ => self,
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.position {
+            Position::Unknown => write!(f, "{}", self.message),
+            Position::Offset(offset) => write!(f, "at byte {}: {}", offset, self.message),
+            Position::LineColumn { line, column } => {
+                write!(f, "line {}, column {}: {}", line, column, self.message)
+            }
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// The byte offset of `slice` in `input`, for building `ParseError::at_offset`
+/// errors from pieces of the input such as lines or tokens. `slice` must be a
+/// subslice of `input`, like those returned by `str::lines` or
+/// `str::split_whitespace`.
+pub fn offset_in(input: &str, slice: &str) -> usize {
+    let start = input.as_ptr() as usize;
+    let offset = (slice.as_ptr() as usize).wrapping_sub(start);
+
+    debug_assert!(
+        offset <= input.len() && offset + slice.len() <= input.len(),
+        "{:?} isn't a slice of the input",
+        slice
+    );
+
+    offset
+}
@@ -0,0 +1,245 @@
+//! Shared 2D geometry: points, distances, bounding boxes and neighbourhoods.
+//!
+//! Coordinates have `x` increasing to the right and `y` increasing downward,
+//! matching the puzzle inputs and `gridly`'s rows and columns.
+
+use std::cmp::Ordering;
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use gridly::prelude::*;
+use regex::Captures;
+
+use crate::error::ParseError;
+use crate::harness::{FromCaptures, RegexExtractor};
+use crate::iter::BetterIterator;
+
+/// A point on the plane, or an offset between two points.
+///
+/// Points are ordered in reading order: top to bottom, then left to right.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+/// The offsets to the 4 orthogonally adjacent points, clockwise from up
+pub const ORTHOGONAL: [Point; 4] = [
+    Point::new(0, -1),
+    Point::new(1, 0),
+    Point::new(0, 1),
+    Point::new(-1, 0),
+];
+
+/// The offsets to all 8 adjacent points, clockwise from up
+pub const ADJACENT: [Point; 8] = [
+    Point::new(0, -1),
+    Point::new(1, -1),
+    Point::new(1, 0),
+    Point::new(1, 1),
+    Point::new(0, 1),
+    Point::new(-1, 1),
+    Point::new(-1, 0),
+    Point::new(-1, -1),
+];
+
+impl Point {
+    pub const ORIGIN: Point = Point::new(0, 0);
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Point { x, y }
+    }
+
+    /// Parse a point from a pair of named capture groups
+    pub fn from_named_captures(caps: &Captures, x: &str, y: &str) -> Result<Self, ParseError> {
+        match (caps.try_parse_named(x), caps.try_parse_named(y)) {
+            (Ok(x), Ok(y)) => Ok(Point { x, y }),
+            (x, y) => Err(ParseError::aggregate(x.err().into_iter().chain(y.err())).unwrap()),
+        }
+    }
+
+    /// The taxicab distance between two points
+    pub fn manhattan(self, other: Point) -> i64 {
+        let delta = self - other;
+        delta.x.abs() + delta.y.abs()
+    }
+
+    /// The chessboard distance between two points, where diagonal steps
+    /// count as 1
+    pub fn chebyshev(self, other: Point) -> i64 {
+        let delta = self - other;
+        delta.x.abs().max(delta.y.abs())
+    }
+
+    /// The 4 orthogonally adjacent points, clockwise from up
+    pub fn neighbours4(self) -> impl Iterator<Item = Point> + Clone {
+        ORTHOGONAL.iter().map(move |&offset| self + offset)
+    }
+
+    /// All 8 adjacent points, clockwise from up
+    pub fn neighbours8(self) -> impl Iterator<Item = Point> + Clone {
+        ADJACENT.iter().map(move |&offset| self + offset)
+    }
+}
+
+impl Ord for Point {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.y, self.x).cmp(&(other.y, other.x))
+    }
+}
+
+impl PartialOrd for Point {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+/// Parses the capture groups named `x` and `y`
+impl FromCaptures for Point {
+    fn from_captures(caps: &Captures) -> Result<Self, ParseError> {
+        Point::from_named_captures(caps, "x", "y")
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Point) -> Point {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, rhs: Point) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Point) -> Point {
+        Point::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, rhs: Point) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, rhs: i64) -> Point {
+        Point::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl From<(i64, i64)> for Point {
+    fn from((x, y): (i64, i64)) -> Self {
+        Point::new(x, y)
+    }
+}
+
+impl From<Location> for Point {
+    fn from(location: Location) -> Self {
+        Point::new(location.column.0 as i64, location.row.0 as i64)
+    }
+}
+
+impl From<Point> for Location {
+    fn from(point: Point) -> Self {
+        Location::new(point.y as isize, point.x as isize)
+    }
+}
+
+impl From<Vector> for Point {
+    fn from(vector: Vector) -> Self {
+        Point::new(vector.columns.0 as i64, vector.rows.0 as i64)
+    }
+}
+
+impl From<Point> for Vector {
+    fn from(point: Point) -> Self {
+        Vector::new(point.y as isize, point.x as isize)
+    }
+}
+
+/// An axis-aligned rectangle, inclusive of both corners
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Bounds {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl Bounds {
+    /// The smallest bounding box containing all of the points, or `None` if
+    /// there are none.
+    pub fn of(points: impl IntoIterator<Item = Point>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+
+        Some(points.fold(Bounds { min: first, max: first }, |bounds, point| Bounds {
+            min: Point::new(bounds.min.x.min(point.x), bounds.min.y.min(point.y)),
+            max: Point::new(bounds.max.x.max(point.x), bounds.max.y.max(point.y)),
+        }))
+    }
+
+    pub fn width(&self) -> i64 {
+        self.max.x - self.min.x + 1
+    }
+
+    pub fn height(&self) -> i64 {
+        self.max.y - self.min.y + 1
+    }
+
+    pub fn area(&self) -> i64 {
+        self.width() * self.height()
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+
+    /// True if the point is inside the box, on one of its edges
+    pub fn on_border(&self, point: Point) -> bool {
+        self.contains(point)
+            && (point.x == self.min.x
+                || point.x == self.max.x
+                || point.y == self.min.y
+                || point.y == self.max.y)
+    }
+
+    /// Grow the box by `margin` in every direction
+    pub fn expand(&self, margin: i64) -> Self {
+        let margin = Point::new(margin, margin);
+
+        Bounds {
+            min: self.min - margin,
+            max: self.max + margin,
+        }
+    }
+
+    /// Every point in the box, in reading order
+    pub fn points(&self) -> impl Iterator<Item = Point> + Clone {
+        (self.min.y..=self.max.y)
+            .cartesian(self.min.x..=self.max.x)
+            .map(|(y, x)| Point::new(x, y))
+    }
+}
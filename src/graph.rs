@@ -0,0 +1,346 @@
+//! A dependency graph, and topological orderings of it.
+
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap};
+use std::error::Error;
+use std::fmt::{self, Debug, Display, Formatter};
+
+use joinery::prelude::*;
+
+/// A set of steps, each of which may have to wait for some others (its
+/// prerequisites) to be done first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DepGraph<T: Ord> {
+    /// Each step's prerequisites
+    prereqs: BTreeMap<T, BTreeSet<T>>,
+
+    /// The steps that are waiting on each step
+    dependents: BTreeMap<T, BTreeSet<T>>,
+}
+
+impl<T: Ord> Default for DepGraph<T> {
+    fn default() -> Self {
+        DepGraph {
+            prereqs: BTreeMap::new(),
+            dependents: BTreeMap::new(),
+        }
+    }
+}
+
+impl<T: Ord + Clone> DepGraph<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a step with no dependencies, if it isn't already present
+    pub fn add_step(&mut self, step: T) {
+        self.prereqs.entry(step.clone()).or_default();
+        self.dependents.entry(step).or_default();
+    }
+
+    /// Record that `prereq` must be done before `step`, adding either one if
+    /// it isn't already present
+    pub fn add_dependency(&mut self, prereq: T, step: T) {
+        self.add_step(prereq.clone());
+        self.add_step(step.clone());
+
+        self.prereqs.get_mut(&step).unwrap().insert(prereq.clone());
+        self.dependents.get_mut(&prereq).unwrap().insert(step);
+    }
+}
+
+impl<T: Ord> DepGraph<T> {
+    pub fn len(&self) -> usize {
+        self.prereqs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.prereqs.is_empty()
+    }
+
+    pub fn contains(&self, step: &T) -> bool {
+        self.prereqs.contains_key(step)
+    }
+
+    /// Every step, in order
+    pub fn steps(&self) -> impl Iterator<Item = &T> {
+        self.prereqs.keys()
+    }
+
+    /// The steps that must be done before `step`
+    pub fn prereqs(&self, step: &T) -> impl Iterator<Item = &T> {
+        self.prereqs.get(step).into_iter().flatten()
+    }
+
+    /// The steps that can't be done until `step` is
+    pub fn dependents(&self, step: &T) -> impl Iterator<Item = &T> {
+        self.dependents.get(step).into_iter().flatten()
+    }
+
+    /// Every dependency, as `(prereq, step)` pairs
+    pub fn dependencies(&self) -> impl Iterator<Item = (&T, &T)> {
+        self.dependents
+            .iter()
+            .flat_map(|(prereq, steps)| steps.iter().map(move |step| (prereq, step)))
+    }
+}
+
+impl<T: Ord + Clone> DepGraph<T> {
+    /// Order the steps so that each comes after its prerequisites. Whenever
+    /// several steps are ready, the one with the smallest key goes first,
+    /// with ties broken by the smallest step. This is Kahn's algorithm, with
+    /// a heap of ready steps.
+    pub fn order_by_key<'a, K: Ord>(&'a self, key: impl Fn(&'a T) -> K) -> Result<Vec<T>, Cycle<T>> {
+        let mut waiting_on: BTreeMap<&T, usize> =
+            self.prereqs.iter().map(|(step, prereqs)| (step, prereqs.len())).collect();
+
+        let mut ready: BinaryHeap<Reverse<(K, &T)>> = waiting_on
+            .iter()
+            .filter(|(_, &count)| count == 0)
+            .map(|(&step, _)| Reverse((key(step), step)))
+            .collect();
+
+        let mut order = Vec::with_capacity(self.len());
+
+        while let Some(Reverse((_, step))) = ready.pop() {
+            order.push(step.clone());
+
+            for next in self.dependents(step) {
+                let count = waiting_on.get_mut(next).unwrap();
+                *count -= 1;
+                if *count == 0 {
+                    ready.push(Reverse((key(next), next)));
+                }
+            }
+        }
+
+        if order.len() == self.len() {
+            Ok(order)
+        } else {
+            let blocked = waiting_on
+                .into_iter()
+                .filter(|&(_, count)| count > 0)
+                .map(|(step, _)| step)
+                .collect();
+
+            Err(self.find_cycle(blocked))
+        }
+    }
+
+    /// The order where the alphabetically first ready step always goes first
+    pub fn lexicographic_order(&self) -> Result<Vec<T>, Cycle<T>> {
+        self.order_by_key(|step| step)
+    }
+
+    /// The order where the alphabetically last ready step always goes first
+    pub fn reverse_lexicographic_order(&self) -> Result<Vec<T>, Cycle<T>> {
+        self.order_by_key(Reverse)
+    }
+
+    /// Every valid order of the steps, in lexicographic order. There can be
+    /// factorially many of these, so this is only practical for small or
+    /// heavily constrained graphs.
+    pub fn all_orders(&self) -> Result<Vec<Vec<T>>, Cycle<T>> {
+        // Check for cycles first, so that an empty result means no steps
+        self.lexicographic_order()?;
+
+        let mut waiting_on: BTreeMap<&T, usize> =
+            self.prereqs.iter().map(|(step, prereqs)| (step, prereqs.len())).collect();
+        let mut current = Vec::with_capacity(self.len());
+        let mut orders = Vec::new();
+
+        self.extend_orders(&mut waiting_on, &mut current, &mut orders);
+        Ok(orders)
+    }
+
+    fn extend_orders<'a>(
+        &'a self,
+        waiting_on: &mut BTreeMap<&'a T, usize>,
+        current: &mut Vec<&'a T>,
+        orders: &mut Vec<Vec<T>>,
+    ) {
+        if current.len() == self.len() {
+            orders.push(current.iter().map(|&step| step.clone()).collect());
+            return;
+        }
+
+        let ready: Vec<&T> = waiting_on
+            .iter()
+            .filter(|(_, &count)| count == 0)
+            .map(|(&step, _)| step)
+            .collect();
+
+        for step in ready {
+            // Take the step out of the running while it's in `current`
+            waiting_on.remove(step);
+            self.dependents(step).for_each(|next| *waiting_on.get_mut(next).unwrap() -= 1);
+            current.push(step);
+
+            self.extend_orders(waiting_on, current, orders);
+
+            current.pop();
+            self.dependents(step).for_each(|next| *waiting_on.get_mut(next).unwrap() += 1);
+            waiting_on.insert(step, 0);
+        }
+    }
+
+    /// Given the steps that Kahn's algorithm couldn't order, find a cycle
+    /// among them. Each of them is waiting on at least one other, so
+    /// following prerequisites from any of them must eventually loop.
+    fn find_cycle(&self, blocked: BTreeSet<&T>) -> Cycle<T> {
+        let mut path: Vec<&T> = Vec::new();
+        let mut step = *blocked.iter().next().expect("find_cycle needs blocked steps");
+
+        let start = loop {
+            if let Some(index) = path.iter().position(|&visited| visited == step) {
+                break index;
+            }
+
+            path.push(step);
+            step = self
+                .prereqs(step)
+                .find(|prereq| blocked.contains(prereq))
+                .expect("Blocked steps always have a blocked prerequisite");
+        };
+
+        // The path follows prerequisites backwards; flip it so that each step
+        // must come before the next
+        let cycle = path[start..].iter().rev().map(|&step| step.clone()).collect();
+
+        Cycle {
+            cycle,
+            blocked: blocked.into_iter().cloned().collect(),
+        }
+    }
+}
+
+/// The error when a graph's steps can't be ordered, because some of them
+/// depend on each other
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle<T> {
+    /// Steps that form a cycle: each must come before the next, and the last
+    /// must come before the first
+    pub cycle: Vec<T>,
+
+    /// Every step that can't be ordered: the ones in cycles, and the ones
+    /// waiting on them
+    pub blocked: Vec<T>,
+}
+
+impl<T: Display> Display for Cycle<T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Dependency cycle: {} -> {}",
+            self.cycle.iter().join_with(" -> "),
+            self.cycle[0]
+        )
+    }
+}
+
+impl<T: Display + Debug> Error for Cycle<T> {}
+
+impl<T: Ord> DepGraph<T> {
+    /// Draw the graph in Graphviz DOT format, with an arrow from each
+    /// prerequisite to the steps waiting on it. Use the returned value's
+    /// `Display` implementation to get the DOT source.
+    pub fn dot(&self) -> Dot<'_, T> {
+        Dot {
+            graph: self,
+            order: None,
+            critical_path: None,
+        }
+    }
+}
+
+/// A DOT rendering of a `DepGraph`, with optional highlights. Created by
+/// `DepGraph::dot`.
+#[derive(Debug, Clone, Copy)]
+pub struct Dot<'a, T: Ord> {
+    graph: &'a DepGraph<T>,
+    order: Option<&'a [T]>,
+    critical_path: Option<&'a [T]>,
+}
+
+impl<'a, T: Ord> Dot<'a, T> {
+    /// Number each step by its position in `order`, and link consecutive
+    /// steps with dashed arrows
+    pub fn with_order(self, order: &'a [T]) -> Self {
+        Dot {
+            order: Some(order),
+            ..self
+        }
+    }
+
+    /// Draw these steps, and the dependencies between consecutive ones, in
+    /// bold red
+    pub fn with_critical_path(self, critical_path: &'a [T]) -> Self {
+        Dot {
+            critical_path: Some(critical_path),
+            ..self
+        }
+    }
+}
+
+/// Escape a string for use inside a quoted DOT identifier
+fn escaped(id: &impl Display) -> String {
+    id.to_string().replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn quoted(id: &impl Display) -> String {
+    format!("\"{}\"", escaped(id))
+}
+
+impl<'a, T: Ord + Display> Display for Dot<'a, T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        const CRITICAL: &str = "color=red, penwidth=2";
+
+        let critical_path = self.critical_path.unwrap_or(&[]);
+        let is_critical_edge = |prereq: &T, step: &T| {
+            critical_path
+                .windows(2)
+                .any(|pair| pair[0] == *prereq && pair[1] == *step)
+        };
+
+        writeln!(f, "digraph steps {{")?;
+        writeln!(f, "    node [shape=circle];")?;
+
+        for step in self.graph.steps() {
+            let mut attrs = Vec::new();
+
+            if let Some(position) = self.order.and_then(|order| order.iter().position(|s| s == step)) {
+                attrs.push(format!("label=\"{}\\n#{}\"", escaped(step), position + 1));
+            }
+            if critical_path.contains(step) {
+                attrs.push(CRITICAL.to_string());
+            }
+
+            if attrs.is_empty() {
+                writeln!(f, "    {};", quoted(step))?;
+            } else {
+                writeln!(f, "    {} [{}];", quoted(step), attrs.join(", "))?;
+            }
+        }
+
+        for (prereq, step) in self.graph.dependencies() {
+            if is_critical_edge(prereq, step) {
+                writeln!(f, "    {} -> {} [{}];", quoted(prereq), quoted(step), CRITICAL)?;
+            } else {
+                writeln!(f, "    {} -> {};", quoted(prereq), quoted(step))?;
+            }
+        }
+
+        if let Some(order) = self.order {
+            for pair in order.windows(2) {
+                writeln!(
+                    f,
+                    "    {} -> {} [style=dashed, color=gray, constraint=false];",
+                    quoted(&pair[0]),
+                    quoted(&pair[1])
+                )?;
+            }
+        }
+
+        write!(f, "}}")
+    }
+}
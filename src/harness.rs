@@ -0,0 +1,479 @@
+//! The shared runner for every day. Each day implements `Solution` and is
+//! handed to `run`; reading input, trimming it, and timing the solution all
+//! happen here.
+
+use std::env;
+use std::fmt::Display;
+use std::fs;
+use std::io::{self, IsTerminal, Read};
+use std::path::{Path, PathBuf};
+use std::process::exit;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use crate::bench;
+use crate::alloc::{self, MemoryStats};
+use crate::error::{self, ParseError};
+use crate::report::Report;
+use crate::solution::{Part, Solution};
+
+#[inline(always)]
+pub fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    let end = Instant::now();
+    (result, end - start)
+}
+
+pub trait ReadString: Read {
+    fn read_string(&mut self) -> io::Result<String> {
+        let mut data = String::new();
+        self.read_to_string(&mut data).map(|_| data)
+    }
+}
+
+impl<T: Read> ReadString for T {}
+
+/// How the harness prints the answer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    /// The answer on stdout, and timings on stderr
+    #[default]
+    Text,
+
+    /// A single JSON record on stdout; see `Report`
+    Json,
+}
+
+/// Parsed command line options for a single run
+#[derive(Debug, Clone, Default)]
+pub struct Options {
+    /// Read the puzzle input from this file, rather than from stdin or the
+    /// `inputs` directory
+    pub input: Option<PathBuf>,
+
+    /// Benchmark the solution over this many runs, rather than solving once
+    pub bench: Option<usize>,
+
+    /// Where to append benchmark results, rather than `bench.csv`
+    pub bench_output: Option<PathBuf>,
+
+    pub format: Format,
+
+    /// Report allocation and resident memory statistics
+    pub mem: bool,
+}
+
+fn option_value(flag: &str, value: Option<String>) -> Result<String, String> {
+    value.ok_or_else(|| format!("Missing value for {}", flag))
+}
+
+impl Options {
+    pub const USAGE: &'static str =
+        "[--format text|json] [--mem] [--bench RUNS [--bench-output FILE]] [input file]";
+
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut options = Options::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--bench" => {
+                    let runs = option_value(&arg, args.next())?;
+                    options.bench = match runs.parse() {
+                        Ok(0) | Err(_) => return Err(format!("Invalid number of runs: {}", runs)),
+                        Ok(runs) => Some(runs),
+                    };
+                }
+                "--bench-output" => options.bench_output = Some(option_value(&arg, args.next())?.into()),
+                "--format" => {
+                    options.format = match option_value(&arg, args.next())?.as_str() {
+                        "text" => Format::Text,
+                        "json" => Format::Json,
+                        format => return Err(format!("Unknown format: {}", format)),
+                    };
+                }
+                "--mem" => options.mem = true,
+                flag if flag.starts_with("--") => return Err(format!("Unrecognized option: {}", arg)),
+                _ if options.input.is_some() => return Err(format!("Unexpected argument: {}", arg)),
+                _ => options.input = Some(arg.into()),
+            }
+        }
+
+        if options.bench.is_some() && options.format == Format::Json {
+            return Err("--format json can't be combined with --bench".to_string());
+        }
+
+        if options.bench.is_some() && options.mem {
+            return Err("--mem can't be combined with --bench".to_string());
+        }
+
+        Ok(options)
+    }
+}
+
+/// Command line arguments for the standalone debugging tools like `day7dot`:
+/// an optional input file, and the flags that tool takes, each with a value.
+/// The solver flags like `--bench` are rejected rather than ignored.
+#[derive(Debug, Clone, Default)]
+pub struct ToolArgs {
+    pub input: Option<PathBuf>,
+    values: Vec<(&'static str, String)>,
+}
+
+impl ToolArgs {
+    /// Parse `args`, accepting each of the `flags` given as `(flag, metavar)`
+    /// pairs like `("--ppm", "FILE")`
+    pub fn from_args(
+        flags: &[(&'static str, &'static str)],
+        args: impl IntoIterator<Item = String>,
+    ) -> Result<Self, String> {
+        let mut tool_args = ToolArgs::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            match flags.iter().find(|(flag, _)| *flag == arg) {
+                Some(&(flag, _)) => {
+                    let value = option_value(flag, args.next())?;
+                    tool_args.values.retain(|(existing, _)| *existing != flag);
+                    tool_args.values.push((flag, value));
+                }
+                None if arg.starts_with("--") => return Err(format!("Unrecognized option: {}", arg)),
+                None if tool_args.input.is_some() => return Err(format!("Unexpected argument: {}", arg)),
+                None => tool_args.input = Some(arg.into()),
+            }
+        }
+
+        Ok(tool_args)
+    }
+
+    /// The usage line for a tool taking `flags`
+    pub fn usage(flags: &[(&'static str, &'static str)]) -> String {
+        flags
+            .iter()
+            .map(|(flag, metavar)| format!("[{} {}] ", flag, metavar))
+            .chain(Some("[input file]".to_string()))
+            .collect()
+    }
+
+    /// The value given for `flag`, if it was given
+    pub fn value(&self, flag: &str) -> Option<&str> {
+        self.values.iter().find(|(existing, _)| *existing == flag).map(|(_, value)| value.as_str())
+    }
+}
+
+/// The default location of the input for a day, when it isn't given
+/// explicitly and nothing is piped to stdin
+pub fn default_input_path(day: u32) -> PathBuf {
+    Path::new("inputs").join(format!("day{:02}.txt", day))
+}
+
+/// Find and read the puzzle input. In order, this tries the file given on the
+/// command line, stdin if something is being piped in, and finally
+/// `inputs/dayNN.txt` in the current directory.
+///
+/// Whenever stdin isn't a terminal it's read to the end before falling back to
+/// `inputs/`, so a pipe that's never closed (for example when running from a
+/// script or a background job) blocks here forever. Redirect stdin from
+/// `/dev/null` or pass the input file explicitly in those cases.
+pub fn read_input(day: u32, options: &Options) -> io::Result<String> {
+    let read_file = |path: &Path| {
+        fs::read_to_string(path)
+            .map_err(|err| io::Error::new(err.kind(), format!("{}: {}", path.display(), err)))
+    };
+
+    if let Some(path) = &options.input {
+        return read_file(path);
+    }
+
+    // An empty stdin (like /dev/null) counts as nothing being piped in
+    if !io::stdin().is_terminal() {
+        let input = io::stdin().read_string()?;
+        if !input.trim().is_empty() {
+            return Ok(input);
+        }
+    }
+
+    read_file(&default_input_path(day))
+}
+
+/// Parse the input and solve one part of the puzzle, without any of the
+/// timing or printing that `run` does. Trailing whitespace is trimmed from the
+/// input, but leading whitespace is preserved, since some puzzles (like the
+/// day 13 tracks) are indentation sensitive.
+pub fn solve<S: Solution>(part: Part, input: &str) -> Result<String, ParseError> {
+    solve_timed::<S>(part, input).map(|solved| solved.answer)
+}
+
+/// An answer, along with how long it took to parse the input and to solve
+/// the puzzle from the parsed input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Solved {
+    pub answer: String,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+/// Like `solve`, but timing the parse and solve stages separately.
+pub fn solve_timed<S: Solution>(part: Part, input: &str) -> Result<Solved, ParseError> {
+    let input = input.trim_end();
+
+    let (parsed, parse_time) = timed(|| S::parse(input));
+    let parsed = parsed.map_err(|err| err.locate(input))?;
+
+    let (answer, solve_time) = timed(|| match part {
+        Part::One => S::part1(&parsed).to_string(),
+        Part::Two => S::part2(&parsed).to_string(),
+    });
+
+    Ok(Solved { answer, parse_time, solve_time })
+}
+
+/// Entry point for the per-day binaries: parse the command line and `run`.
+pub fn main<S: Solution>(part: Part) {
+    let options = Options::from_args(env::args().skip(1)).unwrap_or_else(|err| {
+        let name = env::args().next().unwrap_or_default();
+        eprintln!("{}\nUsage: {} {}", err, name, Options::USAGE);
+        exit(2);
+    });
+
+    run::<S>(part, &options)
+}
+
+/// Read the input, then `solve` and print the given part. If the input can't
+/// be read or is malformed, the error is reported and the process exits with
+/// an error code.
+pub fn run<S: Solution>(part: Part, options: &Options) {
+    let ((), total_duration) = timed(move || {
+        let input = read_input(S::DAY, options).unwrap_or_else(|err| {
+            eprintln!("Error reading input: {}", err);
+            exit(1);
+        });
+
+        if let Some(runs) = options.bench {
+            let (solution, stats) = bench::bench::<S>(part, &input, runs).unwrap_or_else(|err| {
+                eprintln!("Error parsing input: {}", err);
+                exit(1);
+            });
+            println!("{}", solution);
+
+            eprintln!("Benchmark: {}", stats);
+
+            let output = options.bench_output.as_deref().unwrap_or_else(|| Path::new("bench.csv"));
+            if let Err(err) = bench::append_csv(output, S::DAY, part, &stats) {
+                eprintln!("Error writing benchmark results to {}: {}", output.display(), err);
+                exit(1);
+            }
+
+            return;
+        }
+
+        if options.format == Format::Json {
+            let mut report = Report::measure::<S>(part, &input).unwrap_or_else(|err| {
+                eprintln!("Error parsing input: {}", err);
+                exit(1);
+            });
+            if options.mem {
+                report.memory = Some(MemoryStats::read());
+            }
+            println!("{}", report.to_json());
+
+            return;
+        }
+
+        let (solved, usage) = alloc::measure(|| solve_timed::<S>(part, &input));
+        let solved = solved.unwrap_or_else(|err| {
+            eprintln!("Error parsing input: {}", err);
+            exit(1);
+        });
+        println!("{}", solved.answer);
+
+        eprintln!("Parse duration: {:?}", solved.parse_time);
+        eprintln!("Algorithm duration: {:?}", solved.solve_time);
+
+        if options.mem {
+            eprintln!("Allocations: {}", usage);
+            eprintln!("Memory: {}", MemoryStats::read());
+        }
+    });
+    eprintln!("Total duration: {:?}", total_duration);
+}
+
+/// Entry point for the standalone debugging tools: parse the command line,
+/// then read and parse the input the same way `run` does. Usage, input and
+/// parse errors are reported and exit the process.
+pub fn load<S: Solution>(flags: &[(&'static str, &'static str)]) -> (S::Input, ToolArgs) {
+    let args = ToolArgs::from_args(flags, env::args().skip(1)).unwrap_or_else(|err| {
+        let name = env::args().next().unwrap_or_default();
+        eprintln!("{}\nUsage: {} {}", err, name, ToolArgs::usage(flags));
+        exit(2);
+    });
+
+    let options = Options { input: args.input.clone(), ..Options::default() };
+    let input = read_input(S::DAY, &options).unwrap_or_else(|err| {
+        eprintln!("Error reading input: {}", err);
+        exit(1);
+    });
+
+    let input = input.trim_end();
+    let parsed = S::parse(input).unwrap_or_else(|err| {
+        eprintln!("Error parsing input: {}", err.locate(input));
+        exit(1);
+    });
+
+    (parsed, args)
+}
+
+pub trait RegexExtractor<'t> {
+    fn try_field<T>(&self, index: usize) -> Result<T, ParseError>
+    where
+        &'t str: Into<T>;
+
+    fn try_parse<T: FromStr>(&self, index: usize) -> Result<T, ParseError>
+    where
+        T::Err: Display;
+
+    fn try_parse_named<T: FromStr>(&self, name: &str) -> Result<T, ParseError>
+    where
+        T::Err: Display;
+
+    #[inline]
+    fn field<T>(&self, index: usize) -> T
+    where
+        &'t str: Into<T>,
+    {
+        self.try_field(index).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    #[inline]
+    fn parse<T: FromStr>(&self, index: usize) -> T
+    where
+        T::Err: Display,
+    {
+        self.try_parse(index).unwrap_or_else(|err| panic!("{}", err))
+    }
+}
+
+impl<'t> RegexExtractor<'t> for regex::Captures<'t> {
+    #[inline]
+    fn try_field<T>(&self, index: usize) -> Result<T, ParseError>
+    where
+        &'t str: Into<T>,
+    {
+        self.get(index)
+            .map(|group| group.as_str().into())
+            .ok_or_else(move || {
+                let start = self.get(0).map(|whole| whole.start()).unwrap_or(0);
+                ParseError::at_offset(start, format!("Group {} didn't match anything", index))
+            })
+    }
+
+    #[inline]
+    fn try_parse<T: FromStr>(&self, index: usize) -> Result<T, ParseError>
+    where
+        T::Err: Display,
+    {
+        let field: &str = self.try_field(index)?;
+
+        field.parse().map_err(move |err| {
+            let start = self.get(index).unwrap().start();
+            ParseError::at_offset(
+                start,
+                format!("Failed to parse group {} \"{}\": {}", index, field, err),
+            )
+        })
+    }
+
+    #[inline]
+    fn try_parse_named<T: FromStr>(&self, name: &str) -> Result<T, ParseError>
+    where
+        T::Err: Display,
+    {
+        let group = self.name(name).ok_or_else(move || {
+            let start = self.get(0).map(|whole| whole.start()).unwrap_or(0);
+            ParseError::at_offset(start, format!("Group {:?} didn't match anything", name))
+        })?;
+
+        group.as_str().parse().map_err(move |err| {
+            ParseError::at_offset(
+                group.start(),
+                format!("Failed to parse group {:?} \"{}\": {}", name, group.as_str(), err),
+            )
+        })
+    }
+}
+
+/// Types that can be built from the named groups of a regex match. Usually
+/// implemented with `from_captures!`.
+pub trait FromCaptures: Sized {
+    fn from_captures(caps: &regex::Captures) -> Result<Self, ParseError>;
+}
+
+/// Build a `T` from the match of `pattern` in each non-blank line of `input`.
+/// Unlike `captures_iter`, a line that doesn't match is an error rather than
+/// being skipped. Error positions are offsets into `input`.
+pub fn parse_lines<T: FromCaptures>(input: &str, pattern: &regex::Regex) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let offset = error::offset_in(input, line);
+
+            match pattern.captures(line) {
+                Some(caps) => T::from_captures(&caps).map_err(|err| err.offset_by(offset)),
+                None => Err(ParseError::at_offset(offset, format!("Unrecognized line {:?}", line))),
+            }
+        })
+        .collect()
+}
+
+/// Define a struct and implement `FromCaptures` for it. Each field is parsed
+/// with `FromStr` from the named group with the same name as the field. If
+/// any fields fail, all of the failures are reported together.
+///
+/// ```ignore
+/// from_captures! {
+///     #[derive(Debug, Clone, Copy)]
+///     struct Coords {
+///         x: i32,
+///         y: i32,
+///     }
+/// }
+///
+/// let pattern = Regex::new(r"(?P<x>\d+), (?P<y>\d+)").unwrap();
+/// let coords = Coords::from_captures(&pattern.captures("1, 2").unwrap())?;
+/// ```
+#[macro_export]
+macro_rules! from_captures {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $($field_vis:vis $field:ident : $type:ty),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis struct $name {
+            $($field_vis $field: $type,)*
+        }
+
+        impl $crate::harness::FromCaptures for $name {
+            fn from_captures(caps: &::regex::Captures) -> Result<Self, $crate::error::ParseError> {
+                use $crate::harness::RegexExtractor;
+
+                let mut errors = Vec::new();
+
+                $(
+                    let $field: Option<$type> = caps
+                        .try_parse_named(stringify!($field))
+                        .map_err(|err| errors.push(err))
+                        .ok();
+                )*
+
+                match ($($field,)*) {
+                    ($(Some($field),)*) => Ok($name { $($field,)* }),
+                    _ => Err($crate::error::ParseError::aggregate(errors).unwrap()),
+                }
+            }
+        }
+    };
+}
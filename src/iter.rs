@@ -0,0 +1,457 @@
+//! Extra iterator adapters that keep getting rewritten by hand in the day
+//! modules. Import `BetterIterator` to get them on every iterator.
+
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use std::fmt::{self, Debug, Display, Formatter};
+use std::hash::Hash;
+use std::iter::FusedIterator;
+
+/// An iterator with room to push back one item at each end. When the inner
+/// iterator is exhausted, each end can take the item pushed back at the other.
+#[derive(Debug, Clone)]
+struct Buffered<I: Iterator> {
+    front: Option<I::Item>,
+    iter: I,
+    back: Option<I::Item>,
+}
+
+impl<I: Iterator> Buffered<I> {
+    fn new(iter: I) -> Self {
+        Buffered {
+            front: None,
+            iter,
+            back: None,
+        }
+    }
+
+    fn pop_front(&mut self) -> Option<I::Item> {
+        self.front
+            .take()
+            .or_else(|| self.iter.next())
+            .or_else(|| self.back.take())
+    }
+
+    fn push_front(&mut self, item: I::Item) {
+        debug_assert!(self.front.is_none());
+        self.front = Some(item);
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let buffered = self.front.is_some() as usize + self.back.is_some() as usize;
+        let (min, max) = self.iter.size_hint();
+
+        (
+            min.saturating_add(buffered),
+            max.and_then(|max| max.checked_add(buffered)),
+        )
+    }
+}
+
+impl<I: DoubleEndedIterator> Buffered<I> {
+    fn pop_back(&mut self) -> Option<I::Item> {
+        self.back
+            .take()
+            .or_else(|| self.iter.next_back())
+            .or_else(|| self.front.take())
+    }
+
+    fn push_back(&mut self, item: I::Item) {
+        debug_assert!(self.back.is_none());
+        self.back = Some(item);
+    }
+}
+
+/// How `DedupWithCount` and `UniqueIterator` decide whether two adjacent
+/// items belong to the same run
+pub trait SameRun<T> {
+    fn same_run(&mut self, a: &T, b: &T) -> bool;
+}
+
+/// Adjacent items are in the same run if they're equal
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ByEq;
+
+impl<T: PartialEq> SameRun<T> for ByEq {
+    fn same_run(&mut self, a: &T, b: &T) -> bool {
+        a == b
+    }
+}
+
+/// Adjacent items are in the same run if they have equal keys
+#[derive(Debug, Clone, Copy)]
+pub struct ByKey<F>(F);
+
+impl<T, K: PartialEq, F: FnMut(&T) -> K> SameRun<T> for ByKey<F> {
+    fn same_run(&mut self, a: &T, b: &T) -> bool {
+        (self.0)(a) == (self.0)(b)
+    }
+}
+
+/// Iterator adapter over runs of consecutive equal items, yielding the first
+/// item of each run along with its length. Created by
+/// `BetterIterator::dedup_with_count`.
+///
+/// Each run is read in full before it's yielded, from whichever end it's
+/// taken, so this works the same way in both directions.
+pub struct DedupWithCount<I: Iterator, S = ByEq> {
+    iter: Buffered<I>,
+    same_run: S,
+}
+
+// Clone and Debug can't be derived for these adapters, because the derive
+// doesn't see that `Buffered<I>` also needs bounds on `I::Item`
+impl<I: Iterator + Clone, S: Clone> Clone for DedupWithCount<I, S>
+where
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        DedupWithCount {
+            iter: self.iter.clone(),
+            same_run: self.same_run.clone(),
+        }
+    }
+}
+
+impl<I: Iterator + Debug, S: Debug> Debug for DedupWithCount<I, S>
+where
+    I::Item: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("DedupWithCount")
+            .field("iter", &self.iter)
+            .field("same_run", &self.same_run)
+            .finish()
+    }
+}
+
+impl<I: Iterator, S: SameRun<I::Item>> Iterator for DedupWithCount<I, S> {
+    type Item = (usize, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.iter.pop_front()?;
+        let mut count = 1;
+
+        while let Some(item) = self.iter.pop_front() {
+            if self.same_run.same_run(&first, &item) {
+                count += 1;
+            } else {
+                self.iter.push_front(item);
+                break;
+            }
+        }
+
+        Some((count, first))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (min, max) = self.iter.size_hint();
+        (min.min(1), max)
+    }
+}
+
+impl<I: DoubleEndedIterator, S: SameRun<I::Item>> DoubleEndedIterator for DedupWithCount<I, S> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        // Working backwards, the first item of the run is the last one read
+        let mut first = self.iter.pop_back()?;
+        let mut count = 1;
+
+        while let Some(item) = self.iter.pop_back() {
+            if self.same_run.same_run(&item, &first) {
+                first = item;
+                count += 1;
+            } else {
+                self.iter.push_back(item);
+                break;
+            }
+        }
+
+        Some((count, first))
+    }
+}
+
+impl<I: FusedIterator, S: SameRun<I::Item>> FusedIterator for DedupWithCount<I, S> {}
+
+/// Iterator adapter that skips consecutive repeated items, keeping the first
+/// of each run. Created by `BetterIterator::unique` and
+/// `BetterIterator::unique_by_key`.
+pub struct UniqueIterator<I: Iterator, S = ByEq> {
+    runs: DedupWithCount<I, S>,
+}
+
+impl<I: Iterator + Clone, S: Clone> Clone for UniqueIterator<I, S>
+where
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        UniqueIterator {
+            runs: self.runs.clone(),
+        }
+    }
+}
+
+impl<I: Iterator + Debug, S: Debug> Debug for UniqueIterator<I, S>
+where
+    I::Item: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("UniqueIterator").field("runs", &self.runs).finish()
+    }
+}
+
+impl<I: Iterator, S: SameRun<I::Item>> Iterator for UniqueIterator<I, S> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.runs.next().map(|(_, item)| item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.runs.size_hint()
+    }
+}
+
+impl<I: DoubleEndedIterator, S: SameRun<I::Item>> DoubleEndedIterator for UniqueIterator<I, S> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.runs.next_back().map(|(_, item)| item)
+    }
+}
+
+impl<I: FusedIterator, S: SameRun<I::Item>> FusedIterator for UniqueIterator<I, S> {}
+
+/// Iterator adapter over each overlapping pair of items. Created by
+/// `BetterIterator::tuple_windows`.
+#[derive(Debug, Clone)]
+pub struct TupleWindows<I: Iterator> {
+    last: Option<I::Item>,
+    iter: I,
+}
+
+impl<I: Iterator> Iterator for TupleWindows<I>
+where
+    I::Item: Clone,
+{
+    type Item = (I::Item, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = match self.last.take() {
+            Some(last) => last,
+            None => self.iter.next()?,
+        };
+
+        let second = self.iter.next()?;
+        self.last = Some(second.clone());
+        Some((first, second))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (min, max) = self.iter.size_hint();
+
+        // Every remaining item completes a window with the previous one, but
+        // if nothing has been taken yet, the first item only starts one.
+        if self.last.is_some() {
+            (min, max)
+        } else {
+            (min.saturating_sub(1), max.map(|max| max.saturating_sub(1)))
+        }
+    }
+}
+
+impl<I: FusedIterator> FusedIterator for TupleWindows<I> where I::Item: Clone {}
+
+/// Iterator adapter over every pair of items from two iterators, with the
+/// first iterator in the outer loop. Created by `BetterIterator::cartesian`.
+#[derive(Debug, Clone)]
+pub struct Cartesian<I: Iterator, J> {
+    outer: I,
+    current: Option<I::Item>,
+    inner: J,
+    inner_start: J,
+}
+
+impl<I, J> Iterator for Cartesian<I, J>
+where
+    I: Iterator,
+    I::Item: Clone,
+    J: Iterator + Clone,
+{
+    type Item = (I::Item, J::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(current) = &self.current {
+                if let Some(inner) = self.inner.next() {
+                    return Some((current.clone(), inner));
+                }
+            }
+
+            self.current = Some(self.outer.next()?);
+            self.inner = self.inner_start.clone();
+        }
+    }
+
+    /// The rest of the current row, plus a full row for each remaining outer
+    /// item. This is exact when both sides are.
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (row_min, row_max) = match self.current {
+            Some(_) => self.inner.size_hint(),
+            None => (0, Some(0)),
+        };
+        let (outer_min, outer_max) = self.outer.size_hint();
+        let (inner_min, inner_max) = self.inner_start.size_hint();
+
+        let min = outer_min.saturating_mul(inner_min).saturating_add(row_min);
+        let max = match (outer_max, inner_max, row_max) {
+            (Some(0), _, Some(row)) => Some(row),
+            (Some(outer), Some(inner), Some(row)) => outer.checked_mul(inner).and_then(|rows| rows.checked_add(row)),
+            _ => None,
+        };
+
+        (min, max)
+    }
+}
+
+impl<I, J> FusedIterator for Cartesian<I, J>
+where
+    I: FusedIterator,
+    I::Item: Clone,
+    J: Iterator + Clone,
+{
+}
+
+/// The error from `BetterIterator::single`, when there isn't exactly one item
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SingleError {
+    Empty,
+    Multiple,
+}
+
+impl Display for SingleError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            SingleError::Empty => write!(f, "expected exactly one item, but there were none"),
+            SingleError::Multiple => write!(f, "expected exactly one item, but there were several"),
+        }
+    }
+}
+
+impl Error for SingleError {}
+
+pub trait BetterIterator: Iterator + Sized {
+    /// Skip consecutive repeated items, like `Vec::dedup`
+    fn unique(self) -> UniqueIterator<Self>
+    where
+        Self::Item: PartialEq,
+    {
+        UniqueIterator {
+            runs: self.dedup_with_count(),
+        }
+    }
+
+    /// Skip consecutive items with the same key, like `Vec::dedup_by_key`
+    fn unique_by_key<K, F>(self, key: F) -> UniqueIterator<Self, ByKey<F>>
+    where
+        K: PartialEq,
+        F: FnMut(&Self::Item) -> K,
+    {
+        UniqueIterator {
+            runs: DedupWithCount {
+                iter: Buffered::new(self),
+                same_run: ByKey(key),
+            },
+        }
+    }
+
+    /// Collapse runs of consecutive equal items, yielding the first item of
+    /// each run with the length of the run.
+    fn dedup_with_count(self) -> DedupWithCount<Self>
+    where
+        Self::Item: PartialEq,
+    {
+        DedupWithCount {
+            iter: Buffered::new(self),
+            same_run: ByEq,
+        }
+    }
+
+    /// Count the number of times each item appears
+    fn counts(self) -> HashMap<Self::Item, usize>
+    where
+        Self::Item: Hash + Eq,
+    {
+        let mut counts = HashMap::new();
+        self.for_each(|item| *counts.entry(item).or_insert(0) += 1);
+        counts
+    }
+
+    /// Find the minimum and maximum items in a single pass. Like `min` and
+    /// `max`, ties resolve to the first minimum and the last maximum.
+    fn min_max(mut self) -> Option<(Self::Item, Self::Item)>
+    where
+        Self::Item: Ord + Clone,
+    {
+        let first = self.next()?;
+
+        Some(self.fold((first.clone(), first), |(min, max), item| {
+            if item < min {
+                (item, max)
+            } else if item >= max {
+                (min, item)
+            } else {
+                (min, max)
+            }
+        }))
+    }
+
+    /// Get the only item in the iterator, or an error if there are none or
+    /// more than one.
+    fn single(mut self) -> Result<Self::Item, SingleError> {
+        let item = self.next().ok_or(SingleError::Empty)?;
+
+        match self.next() {
+            None => Ok(item),
+            Some(_) => Err(SingleError::Multiple),
+        }
+    }
+
+    /// Iterate over each overlapping pair of items: `(a, b), (b, c), ...`
+    fn tuple_windows(self) -> TupleWindows<Self>
+    where
+        Self::Item: Clone,
+    {
+        TupleWindows {
+            iter: self,
+            last: None,
+        }
+    }
+
+    /// Iterate over every pair of items from this iterator and `other`, with
+    /// this iterator in the outer loop. This replaces the nested
+    /// `flat_map(|x| ys.map(move |y| (x, y)))` pattern.
+    fn cartesian<J>(self, other: J) -> Cartesian<Self, J::IntoIter>
+    where
+        Self::Item: Clone,
+        J: IntoIterator,
+        J::IntoIter: Clone,
+    {
+        let inner = other.into_iter();
+
+        Cartesian {
+            outer: self,
+            current: None,
+            inner: inner.clone(),
+            inner_start: inner,
+        }
+    }
+
+    /// Find the first item that is equal to an earlier item
+    fn first_duplicate(mut self) -> Option<Self::Item>
+    where
+        Self::Item: Hash + Eq + Clone,
+    {
+        let mut seen = HashSet::new();
+        self.find(|item| !seen.insert(item.clone()))
+    }
+}
+
+impl<I: Iterator> BetterIterator for I {}
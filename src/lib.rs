@@ -0,0 +1,14 @@
+#[global_allocator]
+static ALLOC: alloc::Tracking<jemallocator::Jemalloc> = alloc::Tracking(jemallocator::Jemalloc);
+
+pub mod alloc;
+pub mod bench;
+pub mod error;
+pub mod geom;
+pub mod graph;
+pub mod harness;
+pub mod iter;
+pub mod render;
+pub mod report;
+pub mod solution;
+pub mod days;
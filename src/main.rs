@@ -1 +0,0 @@
-day18part1.rs
\ No newline at end of file
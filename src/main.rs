@@ -0,0 +1,46 @@
+use std::env;
+use std::process::exit;
+
+use advent::days;
+use advent::harness::Options;
+use advent::solution::Part;
+
+fn usage() -> String {
+    format!("Usage: advent run <day> <part> {}\n       advent list", Options::USAGE)
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+    match args.as_slice() {
+        ["list"] => days::DAYS.iter().for_each(|entry| println!("{}", entry.day)),
+        ["run", day, part, rest @ ..] => {
+            let (day, part): (u32, Part) = match (day.parse(), part.parse()) {
+                (Ok(day), Ok(part)) => (day, part),
+                _ => {
+                    eprintln!("Day must be a number and part must be 1 or 2\n{}", usage());
+                    exit(2);
+                }
+            };
+
+            let options = Options::from_args(rest.iter().map(|arg| arg.to_string()))
+                .unwrap_or_else(|err| {
+                    eprintln!("{}\n{}", err, usage());
+                    exit(2);
+                });
+
+            match days::find(day) {
+                Some(entry) => (entry.run)(part, &options),
+                None => {
+                    eprintln!("No solver for day {}", day);
+                    exit(1);
+                }
+            }
+        }
+        _ => {
+            eprintln!("{}", usage());
+            exit(2);
+        }
+    }
+}
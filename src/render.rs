@@ -0,0 +1,91 @@
+//! Drawing grids, for debugging: as ASCII text, or as binary PPM images.
+//!
+//! Both renderers take the area to draw as a `Bounds` and a function giving
+//! the glyph or colour of each cell, so any grid-shaped day can use them.
+
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+use crate::geom::{Bounds, Point};
+
+/// A 24-bit colour
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Rgb {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb::new(0, 0, 0);
+    pub const WHITE: Rgb = Rgb::new(255, 255, 255);
+
+    pub const fn new(r: u8, g: u8, b: u8) -> Self {
+        Rgb { r, g, b }
+    }
+
+    /// The same colour at a third of the brightness
+    pub fn dim(self) -> Self {
+        Rgb::new(self.r / 3, self.g / 3, self.b / 3)
+    }
+}
+
+/// A colour for the `index`th item (seed, region, cart, ...). Successive
+/// indexes are spread around the colour wheel, so neighbours are distinct.
+pub fn palette(index: usize) -> Rgb {
+    // Stepping by the golden angle never lands near a previous hue
+    let hue = (index as f64 * 0.618_033_988_749_895).fract() * 6.0;
+    let (saturation, value) = (0.7, 0.95);
+
+    let chroma = value * saturation;
+    let x = chroma * (1.0 - (hue % 2.0 - 1.0).abs());
+    let (r, g, b) = match hue as u32 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+
+    let m = value - chroma;
+    let channel = |c: f64| ((c + m) * 255.0).round() as u8;
+    Rgb::new(channel(r), channel(g), channel(b))
+}
+
+const LABELS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+
+/// A single-character label for the `index`th item. Labels repeat after 62.
+pub fn label(index: usize) -> char {
+    LABELS[index % LABELS.len()] as char
+}
+
+/// Draw every cell in `bounds` as a character, one line per row
+pub fn ascii(bounds: &Bounds, mut glyph: impl FnMut(Point) -> char) -> String {
+    let mut out = String::with_capacity(((bounds.width() + 1) * bounds.height()) as usize);
+
+    for y in bounds.min.y..=bounds.max.y {
+        out.extend((bounds.min.x..=bounds.max.x).map(|x| glyph(Point::new(x, y))));
+        out.push('\n');
+    }
+
+    out
+}
+
+/// Write every cell in `bounds` as one pixel of a binary (P6) PPM image
+pub fn write_ppm(mut out: impl Write, bounds: &Bounds, mut colour: impl FnMut(Point) -> Rgb) -> io::Result<()> {
+    write!(out, "P6\n{} {}\n255\n", bounds.width(), bounds.height())?;
+
+    for point in bounds.points() {
+        let Rgb { r, g, b } = colour(point);
+        out.write_all(&[r, g, b])?;
+    }
+
+    out.flush()
+}
+
+/// Write a PPM image (see `write_ppm`) to a file
+pub fn save_ppm(path: &Path, bounds: &Bounds, colour: impl FnMut(Point) -> Rgb) -> io::Result<()> {
+    write_ppm(BufWriter::new(File::create(path)?), bounds, colour)
+}
@@ -0,0 +1,95 @@
+//! Machine-readable records of a single run, for `--format json`.
+
+use std::fmt::Write;
+use std::time::Duration;
+
+use crate::alloc;
+use crate::error::ParseError;
+use crate::harness::{solve_timed, Solved};
+use crate::solution::{Part, Solution};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub day: u32,
+    pub part: Part,
+    pub answer: String,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+    pub usage: alloc::Usage,
+
+    /// Detailed memory statistics, included with `--mem`
+    pub memory: Option<alloc::MemoryStats>,
+}
+
+impl Report {
+    /// Parse the input and solve the given part, timing each stage and
+    /// tracking the peak allocation across both.
+    pub fn measure<S: Solution>(part: Part, input: &str) -> Result<Self, ParseError> {
+        let (solved, usage) = alloc::measure(|| solve_timed::<S>(part, input));
+        let Solved { answer, parse_time, solve_time } = solved?;
+
+        Ok(Report {
+            day: S::DAY,
+            part,
+            answer,
+            parse_time,
+            solve_time,
+            usage,
+            memory: None,
+        })
+    }
+
+    /// Render this report as a single-line JSON object. Times are in
+    /// nanoseconds.
+    pub fn to_json(&self) -> String {
+        let mut json = format!(
+            r#"{{"day":{},"part":{},"answer":{},"parse_ns":{},"solve_ns":{},"peak_alloc_bytes":{}"#,
+            self.day,
+            self.part,
+            json_string(&self.answer),
+            self.parse_time.as_nanos(),
+            self.solve_time.as_nanos(),
+            self.usage.peak_bytes,
+        );
+
+        if let Some(memory) = &self.memory {
+            write!(
+                json,
+                r#","alloc_bytes":{},"alloc_count":{},"process_peak_resident_bytes":{},"jemalloc_allocated_bytes":{},"jemalloc_resident_bytes":{}"#,
+                self.usage.total_bytes,
+                self.usage.count,
+                json_number(memory.process_peak_resident_bytes),
+                json_number(memory.jemalloc_allocated),
+                json_number(memory.jemalloc_resident),
+            )
+            .unwrap();
+        }
+
+        json.push('}');
+        json
+    }
+}
+
+fn json_number(value: Option<usize>) -> String {
+    value.map_or_else(|| "null".to_string(), |value| value.to_string())
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+
+    out.push('"');
+    out
+}
@@ -0,0 +1,60 @@
+//! The `Solution` trait, which every day implements. Parsing is separate from
+//! solving, so that both parts of a day share a single parser.
+
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use crate::error::ParseError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl FromStr for Part {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, &'static str> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err("Part must be 1 or 2"),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Part::One => '1'.fmt(f),
+            Part::Two => '2'.fmt(f),
+        }
+    }
+}
+
+/// Answer type for a part that hasn't been solved yet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Unsolved;
+
+impl Display for Unsolved {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        "unsolved".fmt(f)
+    }
+}
+
+pub trait Solution {
+    const DAY: u32;
+
+    /// The parsed puzzle input, shared by both parts.
+    type Input;
+
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(input: &Self::Input) -> Self::Part1;
+
+    fn part2(input: &Self::Input) -> Self::Part2;
+}
@@ -1,7 +1,4 @@
-#![allow(unused_imports)]
-
-#[global_allocator]
-static ALLOC: jemallocator::Jemalloc = jemallocator::Jemalloc;
+#![allow(unused_imports, dead_code)]
 
 use std::collections::{HashMap, HashSet};
 use std::error::Error;
@@ -25,77 +22,32 @@ use gridly::prelude::*;
 use gridly_grids::*;
 use generations::*;
 
-// DON'T TOUCH THIS
-#[inline(always)]
-fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
-    let start = Instant::now();
-    let result = f();
-    let end = Instant::now();
-    (result, end - start)
-}
-
-trait ReadString: Read {
-    fn read_string(&mut self) -> io::Result<String> {
-        let mut data = String::new();
-        self.read_to_string(&mut data).map(|_| data)
-    }
-}
+use crate::error::ParseError;
+use crate::geom::{Bounds, Point};
+use crate::harness::RegexExtractor;
+use crate::solution::{Solution, Unsolved};
 
-impl<T: Read> ReadString for T {}
+// CODE GOES HERE
 
-fn main() {
-    let ((), total_duration) = timed(move || {
-        let input = io::stdin().read_string().unwrap_or_else(|err| {
-            eprintln!("Error reading input: {}", err);
-            exit(1);
-        });
 
-        let (solution, duration) = timed(move || solve(&input));
-        println!("{}", solution);
+pub struct DayN;
 
-        eprintln!("Algorithm duration: {:?}", duration);
-    });
-    eprintln!("Total duration: {:?}", total_duration);
-}
+impl Solution for DayN {
+    const DAY: u32 = 0;
 
-trait RegexExtractor<'t> {
-    fn field<T>(&self, index: usize) -> T
-    where
-        &'t str: Into<T>;
+    type Input = ();
+    type Part1 = Unsolved;
+    type Part2 = Unsolved;
 
-    fn parse<T: FromStr>(&self, index: usize) -> T
-    where
-        T::Err: Display;
-}
-
-impl<'t> RegexExtractor<'t> for regex::Captures<'t> {
-    #[inline]
-    fn field<T>(&self, index: usize) -> T
-    where
-        &'t str: Into<T>,
-    {
-        self.get(index)
-            .unwrap_or_else(move || panic!("Group {} didn't match anything", index))
-            .as_str()
-            .into()
+    fn parse(input: &str) -> Result<(), ParseError> {
+        Ok(())
     }
 
-    #[inline]
-    fn parse<T: FromStr>(&self, index: usize) -> T
-    where
-        T::Err: Display,
-    {
-        let field: &str = self.field(index);
-
-        field.parse().unwrap_or_else(move |err| {
-            panic!("Failed to parse group {} \"{}\": {}", index, field, err)
-        })
+    fn part1(input: &()) -> Unsolved {
+        Unsolved
     }
-}
-// CODE GOES HERE
-
-
-#[inline(always)]
-fn solve(input: &str) -> impl Display {
 
+    fn part2(input: &()) -> Unsolved {
+        Unsolved
+    }
 }
@@ -0,0 +1,23 @@
+//! Tests for the allocation counting in `advent::alloc`. These are all in one
+//! test, because `measure` counts allocations from every thread.
+
+use advent::alloc;
+
+#[test]
+fn measure() {
+    let (buffer, usage) = alloc::measure(|| vec![0u8; 10_000]);
+    assert!(usage.peak_bytes >= 10_000, "{}", usage);
+    assert!(usage.total_bytes >= 10_000, "{}", usage);
+    assert!(usage.count >= 1, "{}", usage);
+
+    // Freeing memory from before the call doesn't count against the peak
+    let ((), usage) = alloc::measure(move || drop(buffer));
+    assert_eq!(usage.peak_bytes, 0);
+    assert_eq!(usage.count, 0);
+
+    // Nothing is counted outside of `measure`
+    let outside = vec![0u8; 10_000];
+    let ((), usage) = alloc::measure(|| ());
+    assert_eq!(usage, alloc::Usage::default());
+    drop(outside);
+}
@@ -0,0 +1,72 @@
+//! Check every day against stored personal answers. For each day with both an
+//! `inputs/dayNN.txt` and an `answers/dayNN.txt`, the solver is run on the
+//! input and compared to the answers file, whose first line is the answer to
+//! part 1 and whose second line is the answer to part 2. A missing or blank
+//! line means that part isn't checked, as are the parts in `SKIPPED`.
+
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+
+use advent::days::DAYS;
+use advent::harness::default_input_path;
+use advent::solution::Part;
+
+/// Parts whose solvers don't terminate, or take far too long for a test run:
+/// day 10 part 1 waits forever for someone to read the message, day 11 part 2
+/// is a brute force search, and day 18 part 1 steps a billion generations.
+const SKIPPED: &[(u32, Part)] = &[(10, Part::One), (11, Part::Two), (18, Part::One)];
+
+fn answers_path(day: u32) -> String {
+    format!("answers/day{:02}.txt", day)
+}
+
+#[test]
+fn stored_answers() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let mut failures = Vec::new();
+
+    for entry in DAYS {
+        let input = match fs::read_to_string(root.join(default_input_path(entry.day))) {
+            Ok(input) => input,
+            Err(_) => continue,
+        };
+
+        let answers = match fs::read_to_string(root.join(answers_path(entry.day))) {
+            Ok(answers) => answers,
+            Err(_) => continue,
+        };
+
+        let mut lines = answers.lines().map(str::trim);
+        let expected = [(Part::One, lines.next()), (Part::Two, lines.next())];
+
+        for &(part, expected) in &expected {
+            let expected = match expected {
+                Some(expected) if !expected.is_empty() => expected,
+                _ => continue,
+            };
+
+            if SKIPPED.contains(&(entry.day, part)) {
+                println!("day {} part {}: skipped", entry.day, part);
+                continue;
+            }
+
+            let result = panic::catch_unwind(AssertUnwindSafe(|| (entry.solve)(part, &input)));
+
+            let outcome = match result {
+                Ok(Ok(ref answer)) if answer == expected => {
+                    println!("day {} part {}: ok", entry.day, part);
+                    continue;
+                }
+                Ok(Ok(answer)) => format!("expected {}, got {}", expected, answer),
+                Ok(Err(err)) => format!("parse error: {}", err),
+                Err(_) => "panicked".to_string(),
+            };
+
+            println!("day {} part {}: FAILED ({})", entry.day, part, outcome);
+            failures.push((entry.day, part));
+        }
+    }
+
+    assert!(failures.is_empty(), "Failed: {:?}", failures);
+}
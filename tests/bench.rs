@@ -0,0 +1,48 @@
+//! Tests for the summary statistics behind `--bench`.
+
+use std::time::Duration;
+
+use advent::bench::Stats;
+
+fn millis(samples: &[u64]) -> Vec<Duration> {
+    samples.iter().map(|&ms| Duration::from_millis(ms)).collect()
+}
+
+#[test]
+fn empty() {
+    assert_eq!(Stats::from_samples(&[]), None);
+}
+
+#[test]
+fn single_run() {
+    let stats = Stats::from_samples(&millis(&[7])).unwrap();
+
+    assert_eq!(stats.runs, 1);
+    assert_eq!(stats.min, Duration::from_millis(7));
+    assert_eq!(stats.median, Duration::from_millis(7));
+    assert_eq!(stats.mean, Duration::from_millis(7));
+    assert_eq!(stats.std_dev, Duration::from_millis(0));
+}
+
+#[test]
+fn odd_number_of_runs() {
+    let stats = Stats::from_samples(&millis(&[9, 1, 5])).unwrap();
+
+    assert_eq!(stats.min, Duration::from_millis(1));
+    assert_eq!(stats.median, Duration::from_millis(5));
+    assert_eq!(stats.mean, Duration::from_millis(5));
+    assert_eq!(stats.std_dev, Duration::from_millis(4));
+}
+
+#[test]
+fn even_number_of_runs() {
+    let stats = Stats::from_samples(&millis(&[4, 1, 3, 2])).unwrap();
+
+    assert_eq!(stats.runs, 4);
+    assert_eq!(stats.min, Duration::from_millis(1));
+    assert_eq!(stats.median, Duration::from_micros(2500));
+    assert_eq!(stats.mean, Duration::from_micros(2500));
+
+    // The sample standard deviation: sqrt(5/3) ms
+    assert_eq!(stats.std_dev, Duration::from_nanos(1_290_994));
+}
@@ -0,0 +1,251 @@
+//! Tests for the day 8 license tree, beyond the puzzle example: malformed
+//! input, very deep trees, the arena representation, and writing trees back
+//! out.
+
+use proptest::prelude::*;
+
+use advent::days::day8::{Day8, Day8Arena, Node, Tree, TreeError};
+use advent::solution::Solution;
+
+const EXAMPLE: &[usize] = &[2, 3, 0, 3, 10, 11, 12, 1, 1, 0, 1, 99, 2, 1, 1, 2];
+
+/// A chain of `depth` nodes, each with one child and one metadata entry of 1,
+/// so that every node's value is its child's value.
+fn deep_chain(depth: usize) -> Vec<usize> {
+    let mut numbers = Vec::with_capacity(depth * 3);
+
+    for _ in 1..depth {
+        numbers.extend(&[1, 1]);
+    }
+    numbers.extend(&[0, 1, 7]);
+    numbers.extend(std::iter::repeat_n(1, depth - 1));
+
+    numbers
+}
+
+#[test]
+fn example() {
+    let root = Node::build(EXAMPLE).unwrap();
+
+    assert_eq!(root.meta_score(), 138);
+    assert_eq!(root.value(), 66);
+    assert_eq!(root.children().len(), 2);
+    assert_eq!(root.meta(), [1, 1, 2]);
+}
+
+/// Generate the number stream for a random tree. Metadata entries are small,
+/// so that they often refer to children.
+fn tree_stream() -> impl Strategy<Value = Vec<usize>> {
+    let leaf = prop::collection::vec(0usize..5, 0..4).prop_map(|meta| {
+        let mut numbers = vec![0, meta.len()];
+        numbers.extend(meta);
+        numbers
+    });
+
+    leaf.prop_recursive(6, 200, 5, |inner| {
+        (prop::collection::vec(inner, 1..5), prop::collection::vec(0usize..6, 0..5)).prop_map(
+            |(children, meta)| {
+                let mut numbers = vec![children.len(), meta.len()];
+                children.iter().for_each(|child| numbers.extend(child));
+                numbers.extend(meta);
+                numbers
+            },
+        )
+    })
+}
+
+#[test]
+fn example_arena() {
+    let tree = Tree::build(EXAMPLE).unwrap();
+
+    assert_eq!(tree.len(), 4);
+    assert_eq!(tree.meta_score(), 138);
+    assert_eq!(tree.value(), 66);
+    assert_eq!(tree.meta(tree.root()), [1, 1, 2]);
+    assert_eq!(tree.children(tree.root()).len(), 2);
+}
+
+proptest! {
+    #[test]
+    fn arena_matches_node(numbers in tree_stream()) {
+        let node = Node::build(&numbers).unwrap();
+        let tree = Tree::build(&numbers).unwrap();
+
+        prop_assert_eq!(tree.meta_score(), node.meta_score());
+        prop_assert_eq!(tree.value(), node.value());
+    }
+
+    #[test]
+    fn round_trip(numbers in tree_stream()) {
+        let node = Node::build(&numbers).unwrap();
+        prop_assert_eq!(node.numbers(), numbers.clone());
+
+        let text = node.to_string();
+        let parsed = Day8::parse(&text).unwrap();
+        prop_assert_eq!(parsed.numbers(), numbers);
+    }
+
+    #[test]
+    fn arena_errors_match_node(numbers in prop::collection::vec(0usize..4, 0..30)) {
+        prop_assert_eq!(Tree::build(&numbers).err(), Node::build(&numbers).err());
+    }
+}
+
+#[test]
+fn deep_tree() {
+    let depth = 200_000;
+    let root = Node::build(&deep_chain(depth)).unwrap();
+
+    assert_eq!(root.meta_score(), 7 + (depth - 1));
+    assert_eq!(root.value(), 7);
+
+    let tree = Tree::build(&deep_chain(depth)).unwrap();
+    assert_eq!(tree.meta_score(), 7 + (depth - 1));
+    assert_eq!(tree.value(), 7);
+}
+
+/// A chain of `depth` nodes, each with one child and metadata `[1, 1]`, so
+/// that every node's value is twice its child's, ending in a leaf with the
+/// given metadata
+fn doubling_chain(depth: usize, leaf_meta: &[usize]) -> Vec<usize> {
+    let mut numbers = Vec::new();
+
+    for _ in 1..depth {
+        numbers.extend(&[1, 2]);
+    }
+    numbers.extend(&[0, leaf_meta.len()]);
+    numbers.extend(leaf_meta);
+    for _ in 1..depth {
+        numbers.extend(&[1, 1]);
+    }
+
+    numbers
+}
+
+#[test]
+fn doubling_values() {
+    let numbers = doubling_chain(10, &[1]);
+
+    assert_eq!(Node::build(&numbers).unwrap().value(), 512);
+    assert_eq!(Tree::build(&numbers).unwrap().value(), 512);
+}
+
+/// Every node refers to its child twice, so walking down the tree and
+/// multiplying references would overflow, but every value is 0
+#[test]
+fn deep_doubling_chain_of_zeros() {
+    let numbers = doubling_chain(70, &[]);
+
+    assert_eq!(Node::build(&numbers).unwrap().value(), 0);
+    assert_eq!(Tree::build(&numbers).unwrap().value(), 0);
+
+    let numbers = doubling_chain(100_000, &[]);
+    assert_eq!(Node::build(&numbers).unwrap().value(), 0);
+}
+
+/// 2^64 overflows, so values saturate at `usize::MAX` instead
+#[test]
+fn deep_doubling_chain_saturates() {
+    let numbers = doubling_chain(65, &[1]);
+    let root = Node::build(&numbers).unwrap();
+
+    assert_eq!(root.value(), usize::MAX);
+    assert_eq!(Tree::build(&numbers).unwrap().value(), usize::MAX);
+
+    let pretty = root.pretty().to_string();
+    assert!(pretty.starts_with(&format!("meta [1, 1], value {}\n", usize::MAX)), "{}", pretty);
+    assert!(pretty.contains(&format!("meta [1, 1], value {}\n", 1usize << 63)), "{}", pretty);
+
+    assert_eq!(Node::build(&[0, 2, usize::MAX, 1]).unwrap().meta_score(), usize::MAX);
+    assert_eq!(Tree::build(&[0, 2, usize::MAX, 1]).unwrap().meta_score(), usize::MAX);
+}
+
+#[test]
+fn short_header() {
+    assert_eq!(Node::build(&[]).unwrap_err(), TreeError::ShortHeader { token: 0 });
+    assert_eq!(Node::build(&[1]).unwrap_err(), TreeError::ShortHeader { token: 0 });
+    assert_eq!(Node::build(&[1, 0, 0]).unwrap_err(), TreeError::ShortHeader { token: 2 });
+}
+
+#[test]
+fn short_metadata() {
+    let err = Node::build(&[1, 2, 0, 1, 5, 9]).unwrap_err();
+
+    assert_eq!(err, TreeError::ShortMetadata { node: 0, token: 5, expected: 2, found: 1 });
+    assert_eq!(err.token(), 6);
+}
+
+#[test]
+fn huge_counts_are_errors() {
+    let err = Node::build(&[0, usize::MAX, 1]).unwrap_err();
+    assert_eq!(err, TreeError::ShortMetadata { node: 0, token: 2, expected: usize::MAX, found: 1 });
+
+    let err = Node::build(&[usize::MAX, 0, 0, 0]).unwrap_err();
+    assert_eq!(err, TreeError::ShortHeader { token: 4 });
+}
+
+#[test]
+fn trailing_data() {
+    assert_eq!(Node::build(&[0, 1, 5, 6]).unwrap_err(), TreeError::TrailingData { token: 3 });
+}
+
+#[test]
+fn parse_errors_point_at_the_token() {
+    // The child's metadata runs off the end of the input
+    let input = "1 1\n0 2 3";
+    let err = Day8::parse(input).unwrap_err().locate(input);
+
+    assert_eq!(err.line_column(), Some((2, 6)));
+    assert!(err.message().contains("Node at token 2"), "{}", err);
+
+    let err = Day8::parse("0 1 5 6").unwrap_err().locate("0 1 5 6");
+    assert_eq!(err.to_string(), "line 1, column 7: Unexpected data after the root node, at token 3");
+
+    let err = Day8Arena::parse("0 1 5 6").unwrap_err().locate("0 1 5 6");
+    assert_eq!(err.to_string(), "line 1, column 7: Unexpected data after the root node, at token 3");
+}
+
+#[test]
+fn display() {
+    let root = Node::build(EXAMPLE).unwrap();
+    assert_eq!(root.to_string(), "2 3 0 3 10 11 12 1 1 0 1 99 2 1 1 2");
+}
+
+#[test]
+fn pretty() {
+    let root = Node::build(EXAMPLE).unwrap();
+
+    let expected = "\
+meta [1, 1, 2], value 66
+  meta [10, 11, 12], value 33
+  meta [2], value 0
+    meta [99], value 99
+";
+    assert_eq!(root.pretty().to_string(), expected);
+}
+
+#[test]
+fn hand_crafted() {
+    // A child with no metadata, referred to by an out-of-range entry too
+    let root = Node::new(vec![Node::new(vec![], vec![]), Node::new(vec![], vec![4])], vec![2, 2, 3, 0]);
+
+    assert_eq!(root.to_string(), "2 4 0 0 0 1 4 2 2 3 0");
+    assert_eq!(root.value(), 8);
+    assert_eq!(Node::build(&root.numbers()).unwrap().value(), 8);
+    assert!(root.pretty().to_string().starts_with("meta [2, 2, 3, 0], value 8\n"));
+}
+
+#[test]
+fn deep_tree_round_trip() {
+    let numbers = deep_chain(200_000);
+    let root = Node::build(&numbers).unwrap();
+
+    assert_eq!(root.numbers(), numbers);
+    assert_eq!(Day8::parse(&root.to_string()).unwrap().numbers(), numbers);
+
+    // The indentation grows with depth, so keep this one smaller
+    let pretty = Node::build(&deep_chain(2_000)).unwrap().pretty().to_string();
+    assert_eq!(pretty.lines().count(), 2_000);
+    assert!(pretty.lines().all(|line| line.ends_with("value 7")));
+    assert!(pretty.lines().last().unwrap().starts_with(&" ".repeat(2 * 1_999)));
+}
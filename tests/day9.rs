@@ -0,0 +1,44 @@
+//! Differential tests for day 9: the Rust marble game against the original C
+//! solutions, which `build.rs` compiles into the crate.
+
+use proptest::prelude::*;
+
+use advent::days::day9::{c, Game};
+
+#[test]
+fn puzzle_examples() {
+    let examples = [(9, 25), (10, 1618), (13, 7999), (17, 1104), (21, 6111), (30, 5807)];
+
+    for &(players, last_marble) in &examples {
+        let game = Game { players, last_marble };
+        assert_eq!(game.high_score(), c::part1_high_score(&game), "{:?}", game);
+        assert_eq!(game.high_score(), c::part2_high_score(&game), "{:?}", game);
+    }
+}
+
+#[test]
+fn small_games() {
+    for players in 1..=30 {
+        for last_marble in 0..=250 {
+            let game = Game { players, last_marble };
+            assert_eq!(game.high_score(), c::part1_high_score(&game), "{:?}", game);
+        }
+    }
+}
+
+#[test]
+fn puzzle_input() {
+    let game = Game { players: 459, last_marble: 7_210_300 };
+    assert_eq!(game.high_score(), c::part2_high_score(&game));
+}
+
+proptest! {
+    #[test]
+    fn rust_matches_c(players in 1usize..500, last_marble in 0u32..100_000) {
+        let game = Game { players, last_marble };
+        let expected = game.high_score();
+
+        prop_assert_eq!(c::part1_high_score(&game), expected);
+        prop_assert_eq!(c::part2_high_score(&game), expected);
+    }
+}
@@ -0,0 +1,172 @@
+//! Each day's solver, run against the worked examples from the puzzle text.
+//! Parts without an example (or whose solvers don't terminate yet, like days
+//! 10 and 18) are left out.
+
+use advent::days::day5::{self, Day5};
+use advent::days::day7::{Day7, Schedule};
+use advent::days::day9::{Day9, Game};
+use advent::days::{day13::Day13, day16::Day16, day8::Day8};
+use advent::geom::Point;
+use advent::harness;
+use advent::iter::BetterIterator;
+use advent::solution::{Part, Solution};
+
+fn check<S: Solution>(part: Part, input: &str, expected: &str) {
+    let answer = harness::solve::<S>(part, input)
+        .unwrap_or_else(|err| panic!("Day {} part {} failed to parse: {}", S::DAY, part, err));
+
+    assert_eq!(answer, expected, "Day {} part {}", S::DAY, part);
+}
+
+const DAY5: &str = "\
+1, 1
+1, 6
+8, 3
+3, 4
+5, 5
+8, 9
+";
+
+#[test]
+fn day5_part1() {
+    check::<Day5>(Part::One, DAY5, "17");
+}
+
+#[test]
+fn day5_part1_without_finite_regions() {
+    check::<Day5>(Part::One, "1, 1", "no finite region");
+    check::<Day5>(Part::One, "1, 1\n3, 1\n5, 1", "no finite region");
+}
+
+#[test]
+fn day5_safe_region() {
+    let coords = Day5::parse(DAY5.trim_end()).unwrap();
+
+    assert_eq!(day5::safe_region_size(&coords, 32), 16);
+}
+
+#[test]
+fn day5_safe_region_matches_brute_force() {
+    let coords = Day5::parse(DAY5.trim_end()).unwrap();
+
+    for threshold in (0..100).step_by(7) {
+        let brute_force = (-100..100)
+            .cartesian(-100..100)
+            .map(|(x, y)| Point::new(x, y))
+            .filter(|&cell| coords.iter().map(|coord| coord.manhattan(cell)).sum::<i64>() < threshold)
+            .count();
+
+        assert_eq!(day5::safe_region_size(&coords, threshold), brute_force, "threshold {}", threshold);
+    }
+}
+
+const DAY7: &str = "\
+Step C must be finished before step A.
+Step C must be finished before step F.
+Step A must be finished before step B.
+Step A must be finished before step D.
+Step B must be finished before step E.
+Step D must be finished before step E.
+Step F must be finished before step E.
+";
+
+#[test]
+fn day7_part1() {
+    check::<Day7>(Part::One, DAY7, "CABDFE");
+}
+
+#[test]
+fn day7_schedule() {
+    let steps = Day7::parse(DAY7.trim_end()).unwrap();
+    let schedule = Schedule::run(&steps, 2, |step| step as u32 - 'A' as u32 + 1);
+
+    assert_eq!(schedule.total_time, 15);
+    assert_eq!(
+        schedule.gantt(),
+        "Worker 1: CCCABBDDDDEEEEE\n\
+         Worker 2: ...FFFFFF......\n"
+    );
+
+    let events = schedule.events();
+    assert_eq!(events.len(), 12);
+    assert!(events.windows(2).all(|pair| pair[0].time <= pair[1].time));
+    assert_eq!(events.last().unwrap().step, 'E');
+}
+
+#[test]
+fn day7_critical_path() {
+    let steps = Day7::parse(DAY7.trim_end()).unwrap();
+
+    // D waits for worker 1 to finish B, even though A was done earlier
+    let schedule = Schedule::run(&steps, 2, |step| step as u32 - 'A' as u32 + 1);
+    assert_eq!(schedule.critical_path(&steps), ['C', 'A', 'B', 'D', 'E']);
+
+    // With enough workers, E is held up by F, the longest of its prerequisites
+    let schedule = Schedule::run(&steps, 5, |step| step as u32 - 'A' as u32 + 1);
+    assert_eq!(schedule.critical_path(&steps), ['C', 'F', 'E']);
+}
+
+const DAY8: &str = "2 3 0 3 10 11 12 1 1 0 1 99 2 1 1 2";
+
+#[test]
+fn day8_part1() {
+    check::<Day8>(Part::One, DAY8, "138");
+}
+
+#[test]
+fn day8_part2() {
+    check::<Day8>(Part::Two, DAY8, "66");
+}
+
+#[test]
+fn day9_part1() {
+    check::<Day9>(Part::One, "9 players; last marble is worth 25 points", "32");
+    check::<Day9>(Part::One, "10 players; last marble is worth 1618 points", "8317");
+    check::<Day9>(Part::One, "13 players; last marble is worth 7999 points", "146373");
+    check::<Day9>(Part::One, "17 players; last marble is worth 1104 points", "2764");
+    check::<Day9>(Part::One, "21 players; last marble is worth 6111 points", "54718");
+    check::<Day9>(Part::One, "30 players; last marble is worth 5807 points", "37305");
+}
+
+#[test]
+fn day9_scores() {
+    // In the 25 marble example, only player 5 scores: 23 + 9
+    let scores = Game { players: 9, last_marble: 25 }.scores();
+    assert_eq!(scores, [0, 0, 0, 0, 32, 0, 0, 0, 0]);
+}
+
+#[test]
+fn day9_rejects_bad_input() {
+    assert!(Day9::parse("9 players; last marble is worth many points").is_err());
+    assert!(Day9::parse("0 players; last marble is worth 25 points").is_err());
+}
+
+const DAY13: &str = r"/>-<\
+|   |
+| /<+-\
+| | | v
+\>+</ |
+  |   ^
+  \<->/
+";
+
+#[test]
+fn day13_part2() {
+    check::<Day13>(Part::Two, DAY13, "X: 6, Y: 4");
+}
+
+// The puzzle only gives a single sample, so the test program is made up
+const DAY16: &str = "\
+Before: [3, 2, 1, 1]
+9 2 1 2
+After:  [3, 2, 2, 1]
+
+
+
+9 2 1 2
+";
+
+#[test]
+fn day16_part1() {
+    check::<Day16>(Part::One, DAY16, "1");
+}
@@ -0,0 +1,73 @@
+//! Tests for the shared geometry types in `advent::geom`.
+
+use gridly::prelude::*;
+
+use advent::geom::{Bounds, Point};
+
+#[test]
+fn distances() {
+    let a = Point::new(1, 2);
+    let b = Point::new(-3, 5);
+
+    assert_eq!(a.manhattan(b), 7);
+    assert_eq!(a.chebyshev(b), 4);
+    assert_eq!(a.manhattan(a), 0);
+}
+
+#[test]
+fn neighbours() {
+    let center = Point::new(5, 5);
+
+    let orthogonal: Vec<_> = center.neighbours4().collect();
+    assert_eq!(orthogonal.len(), 4);
+    assert!(orthogonal.iter().all(|&point| center.manhattan(point) == 1));
+
+    let adjacent: Vec<_> = center.neighbours8().collect();
+    assert_eq!(adjacent.len(), 8);
+    assert!(adjacent.iter().all(|&point| center.chebyshev(point) == 1));
+    assert!(orthogonal.iter().all(|point| adjacent.contains(point)));
+}
+
+#[test]
+fn reading_order() {
+    let mut points = vec![Point::new(2, 1), Point::new(0, 2), Point::new(1, 1), Point::new(5, 0)];
+    points.sort();
+
+    assert_eq!(points, [Point::new(5, 0), Point::new(1, 1), Point::new(2, 1), Point::new(0, 2)]);
+}
+
+#[test]
+fn bounds() {
+    let bounds = Bounds::of(vec![Point::new(1, 1), Point::new(8, 3), Point::new(3, 9)]).unwrap();
+
+    assert_eq!(bounds.min, Point::new(1, 1));
+    assert_eq!(bounds.max, Point::new(8, 9));
+    assert_eq!((bounds.width(), bounds.height()), (8, 9));
+    assert_eq!(bounds.points().count() as i64, bounds.area());
+
+    assert!(bounds.contains(Point::new(4, 4)));
+    assert!(!bounds.contains(Point::new(0, 4)));
+    assert!(bounds.on_border(Point::new(8, 5)));
+    assert!(!bounds.on_border(Point::new(7, 5)));
+    assert!(!bounds.on_border(Point::new(9, 5)));
+
+    assert_eq!(Bounds::of(None), None);
+}
+
+#[test]
+fn bounds_points_are_in_reading_order() {
+    let bounds = Bounds { min: Point::new(0, 0), max: Point::new(1, 1) };
+    let points: Vec<_> = bounds.points().collect();
+
+    assert_eq!(points, [Point::new(0, 0), Point::new(1, 0), Point::new(0, 1), Point::new(1, 1)]);
+}
+
+#[test]
+fn gridly_conversions() {
+    let point = Point::new(3, -7);
+    let location: Location = point.into();
+
+    assert_eq!(location, Location::new(-7, 3));
+    assert_eq!(Point::from(location), point);
+    assert_eq!(Point::from(Vector::from(point)), point);
+}
@@ -0,0 +1,143 @@
+//! Tests for the dependency graph in `advent::graph`.
+
+use advent::days::day7::Day7;
+use advent::graph::{Cycle, DepGraph};
+use advent::solution::Solution;
+
+fn graph(dependencies: &[(char, char)]) -> DepGraph<char> {
+    let mut graph = DepGraph::new();
+    for &(prereq, step) in dependencies {
+        graph.add_dependency(prereq, step);
+    }
+    graph
+}
+
+/// The example from the day 7 puzzle
+fn example() -> DepGraph<char> {
+    graph(&[('C', 'A'), ('C', 'F'), ('A', 'B'), ('A', 'D'), ('B', 'E'), ('D', 'E'), ('F', 'E')])
+}
+
+fn is_valid_order(graph: &DepGraph<char>, order: &[char]) -> bool {
+    order.len() == graph.len()
+        && graph.dependencies().all(|(prereq, step)| {
+            let position = |target| order.iter().position(|step| step == target);
+            position(prereq) < position(step)
+        })
+}
+
+#[test]
+fn lexicographic_order() {
+    let order: String = example().lexicographic_order().unwrap().into_iter().collect();
+    assert_eq!(order, "CABDFE");
+}
+
+#[test]
+fn reverse_lexicographic_order() {
+    let order: String = example().reverse_lexicographic_order().unwrap().into_iter().collect();
+    assert_eq!(order, "CFADBE");
+}
+
+#[test]
+fn order_by_key() {
+    // Prefer F whenever it's ready, then alphabetical
+    let graph = example();
+    let order: String = graph
+        .order_by_key(|&step| step != 'F')
+        .unwrap()
+        .into_iter()
+        .collect();
+
+    assert_eq!(order, "CFABDE");
+    assert!(is_valid_order(&graph, &order.chars().collect::<Vec<_>>()));
+}
+
+#[test]
+fn all_orders() {
+    let graph = example();
+    let orders = graph.all_orders().unwrap();
+
+    // After C: A, B, D and F in any order where A precedes B and D, then E
+    assert_eq!(orders.len(), 8);
+    assert!(orders.iter().all(|order| is_valid_order(&graph, order)));
+    assert!(orders.windows(2).all(|pair| pair[0] < pair[1]));
+    assert_eq!(orders[0], graph.lexicographic_order().unwrap());
+    assert_eq!(orders[orders.len() - 1], graph.reverse_lexicographic_order().unwrap());
+}
+
+#[test]
+fn independent_steps() {
+    let mut graph = DepGraph::new();
+    graph.add_step('B');
+    graph.add_step('A');
+
+    assert_eq!(graph.lexicographic_order().unwrap(), ['A', 'B']);
+    assert_eq!(graph.all_orders().unwrap(), [['A', 'B'], ['B', 'A']]);
+    assert_eq!(DepGraph::<char>::new().all_orders().unwrap(), [Vec::<char>::new()]);
+}
+
+#[test]
+fn cycle_detection() {
+    // A -> B -> C -> D -> B, and D -> E
+    let graph = graph(&[('A', 'B'), ('B', 'C'), ('C', 'D'), ('D', 'B'), ('D', 'E')]);
+
+    let Cycle { cycle, blocked } = graph.lexicographic_order().unwrap_err();
+    assert_eq!(blocked, ['B', 'C', 'D', 'E']);
+
+    // The cycle may start anywhere, but must be B -> C -> D in some rotation
+    assert_eq!(cycle.len(), 3);
+    let start = cycle.iter().position(|&step| step == 'B').unwrap();
+    let rotated: String = cycle[start..].iter().chain(&cycle[..start]).collect();
+    assert_eq!(rotated, "BCD");
+
+    assert!(graph.all_orders().is_err());
+}
+
+#[test]
+fn self_dependency_is_a_cycle() {
+    let cycle = graph(&[('A', 'A')]).lexicographic_order().unwrap_err();
+    assert_eq!(cycle.cycle, ['A']);
+    assert_eq!(cycle.to_string(), "Dependency cycle: A -> A");
+}
+
+#[test]
+fn day7_rejects_cycles() {
+    let input = "\
+Step A must be finished before step B.
+Step B must be finished before step A.";
+
+    let err = Day7::parse(input).unwrap_err();
+    assert!(err.to_string().contains("Dependency cycle"), "{}", err);
+}
+
+#[test]
+fn dot() {
+    let graph = example();
+    let dot = graph.dot().to_string();
+
+    assert!(dot.starts_with("digraph steps {\n"));
+    assert!(dot.ends_with('}'));
+    assert!(dot.contains("    \"C\" -> \"A\";\n"));
+    assert_eq!(dot.matches(" -> ").count(), 7);
+}
+
+#[test]
+fn dot_highlights() {
+    let graph = example();
+    let order = graph.lexicographic_order().unwrap();
+    let dot = graph.dot().with_order(&order).with_critical_path(&['C', 'A', 'D']).to_string();
+
+    assert!(dot.contains("\"C\" [label=\"C\\n#1\", color=red, penwidth=2];"));
+    assert!(dot.contains("\"F\" [label=\"F\\n#5\"];"));
+    assert!(dot.contains("\"A\" -> \"D\" [color=red, penwidth=2];"));
+    assert!(dot.contains("\"A\" -> \"B\";"));
+    assert!(dot.contains("\"B\" -> \"D\" [style=dashed, color=gray, constraint=false];"));
+}
+
+#[test]
+fn dot_escapes_quotes() {
+    let mut graph = DepGraph::new();
+    graph.add_dependency("say \"hi\"", "a\\b");
+
+    let dot = graph.dot().to_string();
+    assert!(dot.contains(r#""say \"hi\"" -> "a\\b";"#), "{}", dot);
+}
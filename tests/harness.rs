@@ -0,0 +1,193 @@
+//! Tests for `advent::harness`: building structs from regex matches, parsing
+//! command line options, and finding the input.
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+
+use regex::Regex;
+
+use advent::from_captures;
+use advent::harness::{self, Format, FromCaptures, Options, ToolArgs};
+
+from_captures! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct Claim {
+        id: u32,
+        x: u8,
+        y: u8,
+    }
+}
+
+fn claim_pattern() -> Regex {
+    Regex::new(r"#(?P<id>\S+) @ (?P<x>\S+),(?P<y>\S+)").unwrap()
+}
+
+#[test]
+fn from_captures() {
+    let caps = claim_pattern().captures("#123 @ 3,2").unwrap();
+    assert_eq!(Claim::from_captures(&caps).unwrap(), Claim { id: 123, x: 3, y: 2 });
+}
+
+#[test]
+fn from_captures_reports_every_bad_field() {
+    let input = "#123 @ 300,two";
+    let caps = claim_pattern().captures(input).unwrap();
+    let err = Claim::from_captures(&caps).unwrap_err();
+
+    assert!(err.message().starts_with("2 errors: "), "{}", err);
+    assert!(err.message().contains("\"x\" \"300\""), "{}", err);
+    assert!(err.message().contains("\"y\" \"two\""), "{}", err);
+
+    // Reported at the first bad field
+    assert_eq!(err.locate(input).line_column(), Some((1, 8)));
+}
+
+fn parse_args(args: &[&str]) -> Result<Options, String> {
+    Options::from_args(args.iter().map(|arg| arg.to_string()))
+}
+
+#[test]
+fn options_defaults() {
+    let options = parse_args(&[]).unwrap();
+
+    assert_eq!(options.input, None);
+    assert_eq!(options.bench, None);
+    assert_eq!(options.format, Format::Text);
+    assert!(!options.mem);
+}
+
+#[test]
+fn options_all_flags() {
+    let options = parse_args(&["--bench", "20", "--bench-output", "out.csv", "input.txt"]).unwrap();
+
+    assert_eq!(options.bench, Some(20));
+    assert_eq!(options.bench_output, Some(PathBuf::from("out.csv")));
+    assert_eq!(options.input, Some(PathBuf::from("input.txt")));
+
+    let options = parse_args(&["--mem", "--format", "json"]).unwrap();
+    assert!(options.mem);
+    assert_eq!(options.format, Format::Json);
+}
+
+#[test]
+fn options_errors() {
+    assert_eq!(parse_args(&["--bench", "0"]).unwrap_err(), "Invalid number of runs: 0");
+    assert_eq!(parse_args(&["--bench", "many"]).unwrap_err(), "Invalid number of runs: many");
+    assert_eq!(parse_args(&["--bench"]).unwrap_err(), "Missing value for --bench");
+    assert_eq!(parse_args(&["--format"]).unwrap_err(), "Missing value for --format");
+    assert_eq!(parse_args(&["--format", "xml"]).unwrap_err(), "Unknown format: xml");
+    assert_eq!(parse_args(&["--verbose"]).unwrap_err(), "Unrecognized option: --verbose");
+    assert_eq!(parse_args(&["a.txt", "b.txt"]).unwrap_err(), "Unexpected argument: b.txt");
+
+    assert!(parse_args(&["--bench", "5", "--format", "json"]).is_err());
+    assert!(parse_args(&["--bench", "5", "--mem"]).is_err());
+}
+
+const PPM: &[(&str, &str)] = &[("--ppm", "FILE")];
+
+fn parse_tool_args(args: &[&str]) -> Result<ToolArgs, String> {
+    ToolArgs::from_args(PPM, args.iter().map(|arg| arg.to_string()))
+}
+
+#[test]
+fn tool_args() {
+    let args = parse_tool_args(&[]).unwrap();
+    assert_eq!(args.input, None);
+    assert_eq!(args.value("--ppm"), None);
+
+    let args = parse_tool_args(&["--ppm", "out.ppm", "input.txt"]).unwrap();
+    assert_eq!(args.input, Some(PathBuf::from("input.txt")));
+    assert_eq!(args.value("--ppm"), Some("out.ppm"));
+
+    assert_eq!(ToolArgs::usage(PPM), "[--ppm FILE] [input file]");
+    assert_eq!(ToolArgs::usage(&[]), "[input file]");
+}
+
+#[test]
+fn tool_args_reject_solver_flags() {
+    assert_eq!(parse_tool_args(&["--bench", "5"]).unwrap_err(), "Unrecognized option: --bench");
+    assert_eq!(parse_tool_args(&["--format", "json"]).unwrap_err(), "Unrecognized option: --format");
+    assert_eq!(parse_tool_args(&["--mem"]).unwrap_err(), "Unrecognized option: --mem");
+    assert_eq!(parse_tool_args(&["--ppm"]).unwrap_err(), "Missing value for --ppm");
+    assert_eq!(parse_tool_args(&["a.txt", "b.txt"]).unwrap_err(), "Unexpected argument: b.txt");
+}
+
+/// A fresh, empty directory for a test to work in
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("advent-harness-{}-{}", std::process::id(), name));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn read_input_from_explicit_path() {
+    let dir = scratch_dir("explicit");
+    let path = dir.join("input.txt");
+    fs::write(&path, "1, 1\n").unwrap();
+
+    let options = Options { input: Some(path), ..Options::default() };
+    assert_eq!(harness::read_input(5, &options).unwrap(), "1, 1\n");
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn read_input_reports_the_missing_path() {
+    let dir = scratch_dir("missing");
+    let path = dir.join("nonexistent.txt");
+
+    let options = Options { input: Some(path.clone()), ..Options::default() };
+    let err = harness::read_input(5, &options).unwrap_err();
+
+    let prefix = format!("{}: ", path.display());
+    assert!(err.to_string().starts_with(&prefix), "{}", err);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn default_input_path() {
+    assert_eq!(harness::default_input_path(7), PathBuf::from("inputs/day07.txt"));
+    assert_eq!(harness::default_input_path(16), PathBuf::from("inputs/day16.txt"));
+}
+
+/// With no file argument and nothing on stdin, `inputs/dayNN.txt` in the
+/// current directory is used
+#[test]
+fn run_falls_back_to_inputs_directory() {
+    let dir = scratch_dir("fallback");
+    fs::create_dir(dir.join("inputs")).unwrap();
+    fs::write(dir.join("inputs/day08.txt"), "2 3 0 3 10 11 12 1 1 0 1 99 2 1 1 2\n").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_advent"))
+        .args(["run", "8", "1"])
+        .current_dir(&dir)
+        .stdin(Stdio::null())
+        .output()
+        .unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(String::from_utf8(output.stdout).unwrap().trim(), "138");
+}
+
+#[test]
+fn run_reports_a_missing_input_file() {
+    let dir = scratch_dir("run-missing");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_advent"))
+        .args(["run", "8", "1", "nonexistent.txt"])
+        .current_dir(&dir)
+        .stdin(Stdio::null())
+        .output()
+        .unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.starts_with("Error reading input: nonexistent.txt: "), "{}", stderr);
+    assert!(output.stdout.is_empty());
+}
@@ -0,0 +1,263 @@
+//! Tests for the shared iterator adapters in `advent::iter`.
+
+use std::collections::HashMap;
+
+use proptest::prelude::*;
+
+use advent::iter::{BetterIterator, SingleError};
+
+#[test]
+fn unique_skips_consecutive_repeats() {
+    let items: Vec<_> = [1, 1, 2, 3, 3, 3, 1, 2, 2].iter().unique().collect();
+    assert_eq!(items, [&1, &2, &3, &1, &2]);
+}
+
+#[test]
+fn unique_keeps_the_first_of_each_run() {
+    let items = [(1, 'a'), (1, 'b'), (2, 'c'), (1, 'd'), (1, 'e')];
+
+    let forward: Vec<_> = items.iter().unique_by_key(|item| item.0).collect();
+    assert_eq!(forward, [&(1, 'a'), &(2, 'c'), &(1, 'd')]);
+
+    let backward: Vec<_> = items.iter().unique_by_key(|item| item.0).rev().collect();
+    assert_eq!(backward, [&(1, 'd'), &(2, 'c'), &(1, 'a')]);
+}
+
+#[test]
+fn dedup_with_count() {
+    let runs: Vec<_> = "aaabccdddd".chars().dedup_with_count().collect();
+    assert_eq!(runs, [(3, 'a'), (1, 'b'), (2, 'c'), (4, 'd')]);
+}
+
+/// Pairs of (key, tag). Keys come from a small range so that there are plenty
+/// of runs; tags make it visible which item of a run was kept.
+fn keyed_items() -> impl Strategy<Value = Vec<(u8, u16)>> {
+    prop::collection::vec((0u8..3, any::<u16>()), 0..40)
+}
+
+fn dedup_by_key(items: &[(u8, u16)]) -> Vec<(u8, u16)> {
+    let mut expected = items.to_vec();
+    expected.dedup_by_key(|item| item.0);
+    expected
+}
+
+fn runs_of(items: &[(u8, u16)]) -> Vec<(usize, (u8, u16))> {
+    let mut runs: Vec<(usize, (u8, u16))> = Vec::new();
+
+    for &item in items {
+        match runs.last_mut() {
+            Some((count, first)) if *first == item => *count += 1,
+            _ => runs.push((1, item)),
+        }
+    }
+
+    runs
+}
+
+proptest! {
+    #[test]
+    fn unique_matches_vec_dedup(items in prop::collection::vec(0u8..3, 0..40)) {
+        let mut expected = items.clone();
+        expected.dedup();
+
+        let forward: Vec<u8> = items.iter().cloned().unique().collect();
+        prop_assert_eq!(&forward, &expected);
+
+        let mut backward: Vec<u8> = items.iter().cloned().unique().rev().collect();
+        backward.reverse();
+        prop_assert_eq!(&backward, &expected);
+    }
+
+    #[test]
+    fn unique_by_key_matches_vec_dedup_by_key(items in keyed_items()) {
+        let expected = dedup_by_key(&items);
+
+        let forward: Vec<_> = items.iter().cloned().unique_by_key(|item| item.0).collect();
+        prop_assert_eq!(&forward, &expected);
+
+        let mut backward: Vec<_> = items.iter().cloned().unique_by_key(|item| item.0).rev().collect();
+        backward.reverse();
+        prop_assert_eq!(&backward, &expected);
+    }
+
+    #[test]
+    fn unique_from_both_ends_matches_vec_dedup_by_key(
+        items in keyed_items(),
+        from_back in prop::collection::vec(any::<bool>(), 40),
+    ) {
+        let mut unique = items.iter().cloned().unique_by_key(|item| item.0);
+        let mut front = Vec::new();
+        let mut back = Vec::new();
+
+        for &from_back in from_back.iter().cycle() {
+            let item = if from_back { unique.next_back() } else { unique.next() };
+
+            match item {
+                None => break,
+                Some(item) if from_back => back.push(item),
+                Some(item) => front.push(item),
+            }
+        }
+
+        front.extend(back.into_iter().rev());
+        prop_assert_eq!(front, dedup_by_key(&items));
+    }
+
+    #[test]
+    fn dedup_with_count_matches_runs(items in prop::collection::vec((0u8..2, 0u16..2), 0..40)) {
+        let expected = runs_of(&items);
+
+        let runs: Vec<_> = items.iter().cloned().dedup_with_count().collect();
+        prop_assert_eq!(&runs, &expected);
+        prop_assert_eq!(runs.iter().map(|&(count, _)| count).sum::<usize>(), items.len());
+
+        let mut backward: Vec<_> = items.iter().cloned().dedup_with_count().rev().collect();
+        backward.reverse();
+        prop_assert_eq!(&backward, &expected);
+
+        let deduped: Vec<_> = runs.iter().map(|&(_, item)| item).collect();
+        let mut expected_deduped = items.clone();
+        expected_deduped.dedup();
+        prop_assert_eq!(deduped, expected_deduped);
+    }
+
+    #[test]
+    fn unique_size_hint_is_accurate(
+        items in prop::collection::vec(0u8..3, 0..40),
+        from_back in prop::collection::vec(any::<bool>(), 40),
+    ) {
+        let mut unique = items.iter().unique();
+
+        for &from_back in from_back.iter().cycle() {
+            let (min, max) = unique.size_hint();
+            let remaining = unique.clone().count();
+
+            prop_assert!(min <= remaining, "{} > {}", min, remaining);
+            prop_assert!(max.is_none_or(|max| remaining <= max), "{} > {:?}", remaining, max);
+
+            let item = if from_back { unique.next_back() } else { unique.next() };
+            if item.is_none() {
+                break;
+            }
+        }
+    }
+}
+
+#[test]
+fn counts() {
+    let counts = "abracadabra".chars().counts();
+    let expected: HashMap<char, usize> = [('a', 5), ('b', 2), ('r', 2), ('c', 1), ('d', 1)]
+        .iter()
+        .cloned()
+        .collect();
+
+    assert_eq!(counts, expected);
+    assert!(std::iter::empty::<u8>().counts().is_empty());
+}
+
+#[test]
+fn min_max() {
+    assert_eq!([3, -1, 4, 1, -5, 9, 2].iter().min_max(), Some((&-5, &9)));
+    assert_eq!([7].iter().min_max(), Some((&7, &7)));
+    assert_eq!(std::iter::empty::<i32>().min_max(), None);
+}
+
+/// Ordered only by its key, so that ties are distinguishable by the tag
+#[derive(Debug, Clone, Copy)]
+struct Keyed(i32, char);
+
+impl PartialEq for Keyed {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Eq for Keyed {}
+
+impl PartialOrd for Keyed {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Keyed {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+#[test]
+fn min_max_ties_match_min_and_max() {
+    let items = [Keyed(1, 'a'), Keyed(0, 'b'), Keyed(2, 'c'), Keyed(0, 'd'), Keyed(2, 'e')];
+
+    let (min, max) = items.iter().min_max().unwrap();
+    assert_eq!((min.1, max.1), ('b', 'e'));
+    assert_eq!(min.1, items.iter().min().unwrap().1);
+    assert_eq!(max.1, items.iter().max().unwrap().1);
+}
+
+#[test]
+fn single() {
+    assert_eq!([5].iter().single(), Ok(&5));
+    assert_eq!(std::iter::empty::<i32>().single(), Err(SingleError::Empty));
+    assert_eq!([1, 2].iter().single(), Err(SingleError::Multiple));
+}
+
+#[test]
+fn tuple_windows() {
+    let windows: Vec<_> = (1..=4).tuple_windows().collect();
+    assert_eq!(windows, [(1, 2), (2, 3), (3, 4)]);
+
+    assert_eq!((1..=1).tuple_windows().next(), None);
+    assert_eq!((1..1).tuple_windows().next(), None);
+}
+
+#[test]
+fn tuple_windows_size_hint() {
+    let mut windows = (1..=4).tuple_windows();
+    assert_eq!(windows.size_hint(), (3, Some(3)));
+
+    windows.next();
+    assert_eq!(windows.size_hint(), (2, Some(2)));
+    assert_eq!(windows.count(), 2);
+}
+
+#[test]
+fn cartesian() {
+    let pairs: Vec<_> = (0..2).cartesian("ab".chars()).collect();
+    assert_eq!(pairs, [(0, 'a'), (0, 'b'), (1, 'a'), (1, 'b')]);
+
+    assert_eq!((0..3).cartesian(0..0).next(), None);
+    assert_eq!((0..0).cartesian(0..3).next(), None);
+}
+
+#[test]
+fn cartesian_size_hint() {
+    let mut pairs = (0..3).cartesian(0..4);
+
+    for remaining in (0..=12).rev() {
+        assert_eq!(pairs.size_hint(), (remaining, Some(remaining)));
+        assert_eq!(pairs.next().is_some(), remaining > 0);
+    }
+
+    // Filtering makes the inner side inexact, so the hint is only a bound
+    let pairs = (0..3).cartesian((0..4).filter(|&y| y % 2 == 0));
+    assert_eq!(pairs.size_hint(), (0, Some(12)));
+    assert_eq!(pairs.count(), 6);
+
+    assert_eq!((0..3).cartesian(0..).size_hint(), (usize::MAX, None));
+}
+
+#[test]
+fn cartesian_matches_nested_flat_map() {
+    let nested: Vec<_> = (1..=5).flat_map(|x| (-2..2).map(move |y| (x, y))).collect();
+    let cartesian: Vec<_> = (1..=5).cartesian(-2..2).collect();
+
+    assert_eq!(cartesian, nested);
+}
+
+#[test]
+fn first_duplicate() {
+    assert_eq!([1, 2, 3, 2, 1].iter().first_duplicate(), Some(&2));
+    assert_eq!([1, 2, 3].iter().first_duplicate(), None);
+}
@@ -0,0 +1,125 @@
+//! Malformed input should be reported as a `ParseError` pointing at the
+//! problem, rather than skipped or left to panic in the solver.
+
+use advent::days::day10::Day10;
+use advent::days::day13::Day13;
+use advent::days::day16::{Day16, Day16Backup};
+use advent::days::day5::Day5;
+use advent::days::day7::Day7;
+use advent::days::day9::Day9;
+use advent::error::{self, ParseError};
+use advent::solution::Solution;
+
+fn parse_error<S: Solution>(input: &str) -> ParseError {
+    match S::parse(input) {
+        Ok(_) => panic!("Day {} accepted malformed input {:?}", S::DAY, input),
+        Err(err) => err.locate(input),
+    }
+}
+
+#[test]
+fn unrecognized_lines_are_errors() {
+    let err = parse_error::<Day5>("1, 1\n1 6\n8, 3\n");
+    assert_eq!(err.line_column(), Some((2, 1)));
+    assert!(err.message().contains("\"1 6\""), "{}", err);
+
+    let err = parse_error::<Day7>(
+        "Step C must be finished before step A.\nStep A must be finishd before step B.\n",
+    );
+    assert_eq!(err.line_column(), Some((2, 1)));
+
+    let err = parse_error::<Day10>("position=< 9,  1> velocity=< 0,  2>\nposition=< 7, 0>\n");
+    assert_eq!(err.line_column(), Some((2, 1)));
+}
+
+#[test]
+fn field_errors_point_into_the_line() {
+    let err = parse_error::<Day5>("1, 1\n3, 99999999999999999999\n");
+    assert_eq!(err.line_column(), Some((2, 4)));
+}
+
+#[test]
+fn offset_in() {
+    let input = "ab cd\nef";
+    let offsets: Vec<usize> = input.split_whitespace().map(|token| error::offset_in(input, token)).collect();
+
+    assert_eq!(offsets, [0, 3, 6]);
+    assert_eq!(error::offset_in(input, &input[input.len()..]), input.len());
+}
+
+#[test]
+fn blank_lines_are_skipped() {
+    assert_eq!(Day5::parse("1, 1\n\n  \n8, 3\n").unwrap().len(), 2);
+}
+
+#[test]
+fn day5_needs_coordinates() {
+    assert!(Day5::parse("").is_err());
+    assert!(Day5::parse("\n\n").is_err());
+}
+
+#[test]
+fn day13_needs_an_odd_number_of_carts() {
+    assert!(Day13::parse("-----").is_err());
+    assert!(Day13::parse("->-<-").is_err());
+    assert!(Day13::parse("->---").is_ok());
+}
+
+#[test]
+fn day9_last_marble_too_large() {
+    assert!(Day9::parse("9 players; last marble is worth 42949672 points").is_ok());
+
+    let err = parse_error::<Day9>("9 players; last marble is worth 42949673 points");
+    assert_eq!(err.line_column(), Some((1, 33)));
+    assert!(err.message().contains("at most 42949672"), "{}", err);
+}
+
+const DAY16_SAMPLE: &str = "\
+Before: [3, 2, 1, 1]
+9 2 1 2
+After:  [3, 2, 2, 1]";
+
+#[test]
+fn day16_opcode_out_of_range() {
+    let input = format!("{}\n\n\n\n9 2 1 2\n16 2 1 2\n", DAY16_SAMPLE);
+    let err = parse_error::<Day16>(&input);
+    assert_eq!(err.line_column(), Some((8, 1)));
+    assert!(err.message().contains("opcode out of range"), "{}", err);
+
+    let input = DAY16_SAMPLE.replace("9 2 1 2", "16 2 1 2") + "\n\n\n\n9 2 1 2\n";
+    let err = parse_error::<Day16>(&input);
+    assert_eq!(err.line_column(), Some((2, 1)));
+    assert!(err.message().contains("Opcode 16"), "{}", err);
+}
+
+#[test]
+fn day16_register_out_of_range() {
+    // Opcode 4 is addr, so both inputs are registers
+    let input = format!("{}\n\n\n\n4 0 7 1\n", DAY16_SAMPLE);
+    let err = parse_error::<Day16>(&input);
+    assert_eq!(err.line_column(), Some((7, 1)));
+    assert!(err.message().contains("register out of range"), "{}", err);
+
+    // The output is always a register
+    let input = format!("{}\n\n\n\n9 0 1 4\n", DAY16_SAMPLE);
+    assert_eq!(parse_error::<Day16>(&input).line_column(), Some((7, 1)));
+}
+
+#[test]
+fn day16_backup_contradictory_samples() {
+    // No operation can turn all zeros into all fives
+    let input = "\
+Before: [0, 0, 0, 0]
+9 0 0 0
+After:  [5, 5, 5, 5]
+
+
+
+9 0 0 0
+";
+    let err = parse_error::<Day16Backup>(input);
+    assert_eq!(err.line_column(), Some((1, 1)));
+    assert!(err.message().contains("Opcode 9"), "{}", err);
+
+    assert!(Day16::parse(input).is_ok());
+}
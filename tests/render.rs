@@ -0,0 +1,77 @@
+//! Tests for the ASCII and PPM renderers in `advent::render`.
+
+use std::collections::HashSet;
+use std::env;
+use std::fs;
+
+use advent::days::day5::{Day5, Voronoi};
+use advent::geom::{Bounds, Point};
+use advent::render::{self, Rgb};
+use advent::solution::Solution;
+
+#[test]
+fn ascii() {
+    let bounds = Bounds { min: Point::new(-1, 0), max: Point::new(1, 1) };
+    let drawn = render::ascii(&bounds, |point| if point.x == point.y { '#' } else { '.' });
+
+    assert_eq!(drawn, ".#.\n..#\n");
+}
+
+#[test]
+fn ppm() {
+    let bounds = Bounds { min: Point::new(0, 0), max: Point::new(2, 1) };
+    let mut out = Vec::new();
+
+    render::write_ppm(&mut out, &bounds, |point| Rgb::new(point.x as u8, point.y as u8, 7)).unwrap();
+
+    let header = b"P6\n3 2\n255\n";
+    assert_eq!(&out[..header.len()], header);
+    assert_eq!(&out[header.len()..], [0, 0, 7, 1, 0, 7, 2, 0, 7, 0, 1, 7, 1, 1, 7, 2, 1, 7]);
+}
+
+#[test]
+fn palette_colours_are_distinct() {
+    let colours: HashSet<Rgb> = (0..62).map(render::palette).collect();
+    assert_eq!(colours.len(), 62);
+}
+
+#[test]
+fn day5_voronoi_ascii() {
+    let seeds = Day5::parse("1, 1\n1, 6\n8, 3\n3, 4\n5, 5\n8, 9").unwrap();
+    let drawn = Voronoi::new(&seeds).unwrap().render_ascii();
+
+    let expected_map = "\
+#aaa.ccc
+aaDDEccc
+aDDDEcc#
+.D#DEEcc
+b.DE#EEc
+#b.EEEE.
+bb.EEEff
+bb.EEfff
+bb.ffff#
+";
+    assert!(drawn.starts_with(expected_map), "{}", drawn);
+    assert!(drawn.contains("D (3,4): area 9"));
+    assert!(drawn.contains("E (5,5): area 17"));
+    assert!(drawn.contains("a (1,1): infinite"));
+}
+
+#[test]
+fn day5_voronoi_save_ppm() {
+    let seeds = Day5::parse("1, 1\n1, 6\n8, 3\n3, 4\n5, 5\n8, 9").unwrap();
+    let voronoi = Voronoi::new(&seeds).unwrap();
+
+    let mut written = Vec::new();
+    voronoi.write_ppm(&mut written).unwrap();
+
+    let path = env::temp_dir().join(format!("advent-voronoi-{}.ppm", std::process::id()));
+    render::save_ppm(&path, &voronoi.bounds(), |cell| voronoi.colour(cell)).unwrap();
+    let saved = fs::read(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(saved, written);
+    assert!(saved.starts_with(b"P6\n8 9\n255\n"));
+    assert_eq!(voronoi.colour(Point::new(1, 1)), Rgb::WHITE);
+    assert_eq!(voronoi.colour(Point::new(0, 3)), Rgb::BLACK);
+}
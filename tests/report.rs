@@ -0,0 +1,81 @@
+//! Tests for the `--format json` run report.
+
+use std::env;
+use std::fs;
+use std::process::Command;
+use std::time::Duration;
+
+use advent::alloc::{MemoryStats, Usage};
+use advent::days::day8::Day8;
+use advent::report::Report;
+use advent::solution::Part;
+
+fn report(answer: &str) -> Report {
+    Report {
+        day: 8,
+        part: Part::Two,
+        answer: answer.to_string(),
+        parse_time: Duration::from_micros(15),
+        solve_time: Duration::from_nanos(2500),
+        usage: Usage { peak_bytes: 1024, total_bytes: 4096, count: 3 },
+        memory: None,
+    }
+}
+
+#[test]
+fn json() {
+    assert_eq!(
+        report("66").to_json(),
+        r#"{"day":8,"part":2,"answer":"66","parse_ns":15000,"solve_ns":2500,"peak_alloc_bytes":1024}"#
+    );
+}
+
+#[test]
+fn json_with_memory() {
+    let mut report = report("66");
+    report.memory = Some(MemoryStats {
+        process_peak_resident_bytes: Some(8192),
+        jemalloc_allocated: None,
+        jemalloc_resident: Some(2048),
+    });
+
+    assert!(report.to_json().ends_with(
+        r#""peak_alloc_bytes":1024,"alloc_bytes":4096,"alloc_count":3,"process_peak_resident_bytes":8192,"jemalloc_allocated_bytes":null,"jemalloc_resident_bytes":2048}"#
+    ), "{}", report.to_json());
+}
+
+#[test]
+fn json_escapes_answers() {
+    let json = report("say \"hi\"\\\n\tthere\u{1}é").to_json();
+    assert!(json.contains(r#""answer":"say \"hi\"\\\n\tthere\u0001é","#), "{}", json);
+}
+
+#[test]
+fn measure() {
+    let report = Report::measure::<Day8>(Part::One, "2 3 0 3 10 11 12 1 1 0 1 99 2 1 1 2").unwrap();
+
+    assert_eq!(report.answer, "138");
+    assert!(report.usage.peak_bytes > 0);
+    assert!(report.to_json().starts_with(r#"{"day":8,"part":1,"answer":"138","#));
+}
+
+/// A json run prints one record and nothing else on stdout, even for a
+/// solver with debug output
+#[test]
+fn json_run_prints_only_the_record() {
+    let input = env::temp_dir().join(format!("advent-report-{}.txt", std::process::id()));
+    fs::write(&input, "Before: [3, 2, 1, 1]\n9 2 1 2\nAfter:  [3, 2, 2, 1]\n\n\n\n9 2 1 2\n").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_advent"))
+        .args(["run", "16", "1", "--format", "json"])
+        .arg(&input)
+        .output()
+        .unwrap();
+    fs::remove_file(&input).unwrap();
+
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(stdout.lines().count(), 1, "{}", stdout);
+    assert!(stdout.starts_with(r#"{"day":16,"part":1,"answer":"1","#), "{}", stdout);
+}